@@ -1,9 +1,13 @@
 //! [`Argument`] and related types.
 
-use core::fmt;
+use core::{fmt, mem, ops};
 
 use crate::{
-    format::{Fmt, FormatArgument, Pad, StrFormat, StrLength},
+    format::{
+        private::Sealed, Affixes, Fmt, FormatArgument, MaxLength, Pad, PadFill, StrFormat,
+        StrLength,
+    },
+    transform::StrSink,
     utils::{assert_is_ascii, count_chars, ClippedStr},
     CompileArgs,
 };
@@ -11,7 +15,7 @@ use crate::{
 #[derive(Debug, Clone, Copy)]
 enum ArgumentInner<'a> {
     Str(&'a str, Option<StrFormat>),
-    Char(char),
+    Char(char, Option<StrFormat>),
     Int(i128),
     UnsignedInt(u128),
 }
@@ -20,14 +24,12 @@ impl ArgumentInner<'_> {
     const fn formatted_len(&self) -> StrLength {
         match self {
             Self::Str(s, None) => StrLength::for_str(s),
-            Self::Str(s, Some(fmt)) => match ClippedStr::new(s, fmt.clip_at) {
-                ClippedStr::Full(_) => StrLength::for_str(s),
-                ClippedStr::Clipped(bytes) => StrLength {
-                    bytes: bytes.len() + fmt.using.len(),
-                    chars: fmt.clip_at + count_chars(fmt.using),
-                },
-            },
-            Self::Char(c) => StrLength::for_char(*c),
+            Self::Str(s, Some(fmt)) => fmt.apply(s, StrSink::measure()).len(),
+            Self::Char(c, None) => StrLength::for_char(*c),
+            Self::Char(c, Some(fmt)) => {
+                let encoded = CompileArgs::<4>::new().write_char(*c);
+                fmt.apply(encoded.as_str(), StrSink::measure()).len()
+            }
             Self::Int(value) => {
                 let bytes = (*value < 0) as usize + log_10_ceil(value.unsigned_abs());
                 StrLength::both(bytes)
@@ -35,6 +37,16 @@ impl ArgumentInner<'_> {
             Self::UnsignedInt(value) => StrLength::both(log_10_ceil(*value)),
         }
     }
+
+    /// Checks whether this argument is considered empty for the purposes of affixes.
+    const fn is_empty(&self) -> bool {
+        match self {
+            Self::Str(..) => self.formatted_len().bytes == 0,
+            Self::Char(..) => false,
+            Self::Int(value) => *value == 0,
+            Self::UnsignedInt(value) => *value == 0,
+        }
+    }
 }
 
 /// Generalized argument in crate macros.
@@ -43,16 +55,33 @@ impl ArgumentInner<'_> {
 pub struct Argument<'a> {
     inner: ArgumentInner<'a>,
     pad: Option<Pad>,
+    affixes: Affixes,
 }
 
 impl Argument<'_> {
+    /// Returns affixes actually output for the argument; they are omitted for empty values
+    /// if the format requires so.
+    const fn output_affixes(&self) -> Affixes {
+        if self.affixes.skip_if_empty && self.inner.is_empty() {
+            Affixes::NONE
+        } else {
+            self.affixes
+        }
+    }
+
+    /// Returns the length of the argument with affixes, but without padding.
+    const fn non_padded_len(&self) -> StrLength {
+        self.inner.formatted_len().sum(self.output_affixes().len())
+    }
+
     /// Returns the formatted length of the argument in bytes.
     pub const fn formatted_len(&self) -> usize {
-        let non_padded_len = self.inner.formatted_len();
+        let non_padded_len = self.non_padded_len();
         if let Some(pad) = &self.pad {
-            if pad.width > non_padded_len.chars {
-                let pad_char_count = pad.width - non_padded_len.chars;
-                pad_char_count * pad.using.len_utf8() + non_padded_len.bytes
+            let non_padded_width = pad.measure(&non_padded_len);
+            if pad.width > non_padded_width {
+                let pad_char_count = pad.width - non_padded_width;
+                pad.using.byte_len(pad_char_count) + non_padded_len.bytes
             } else {
                 // The non-padded string is longer than the pad width; it won't be padded
                 non_padded_len.bytes
@@ -63,7 +92,56 @@ impl Argument<'_> {
     }
 }
 
-const fn log_10_ceil(mut value: u128) -> usize {
+impl Argument<'_> {
+    /// Sets the padding width for this argument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the argument does not have dynamic padding (e.g., [`Fmt::pad_left_dyn()`]),
+    /// or if `width` exceeds the maximum padding width.
+    #[track_caller]
+    #[must_use]
+    pub const fn with_pad_width(mut self, width: usize) -> Self {
+        let Some(mut pad) = self.pad else {
+            panic!("Padding width can only be specified for formats with dynamic padding");
+        };
+        assert!(
+            pad.is_dynamic,
+            "Padding width can only be specified for formats with dynamic padding"
+        );
+        if width > pad.max_width {
+            crate::compile_panic!(
+                "Padding width ", width => crate::fmt::<usize>(), " exceeds the maximum width ",
+                pad.max_width => crate::fmt::<usize>()
+            );
+        }
+        pad.width = width;
+        self.pad = Some(pad);
+        self
+    }
+
+    /// Checks whether the formatted argument consists entirely of ASCII chars.
+    pub const fn is_ascii(&self) -> bool {
+        if let Some(pad) = &self.pad {
+            if !pad.using.is_ascii() {
+                return false;
+            }
+        }
+        if !self.output_affixes().is_ascii() {
+            return false;
+        }
+        match self.inner {
+            ArgumentInner::Char(c, None) => c.is_ascii(),
+            ArgumentInner::Str(..) | ArgumentInner::Char(_, Some(_)) => {
+                let len = self.inner.formatted_len();
+                len.bytes == len.chars
+            }
+            ArgumentInner::Int(_) | ArgumentInner::UnsignedInt(_) => true,
+        }
+    }
+}
+
+pub(crate) const fn log_10_ceil(mut value: u128) -> usize {
     if value == 0 {
         return 1;
     }
@@ -77,7 +155,7 @@ const fn log_10_ceil(mut value: u128) -> usize {
 }
 
 impl<const CAP: usize> CompileArgs<CAP> {
-    const fn write_u128(self, mut value: u128) -> Self {
+    pub(crate) const fn write_u128(self, mut value: u128) -> Self {
         let new_len = self.len + log_10_ceil(value);
         let mut buffer = self.buffer;
         let mut pos = new_len - 1;
@@ -109,36 +187,62 @@ impl<const CAP: usize> CompileArgs<CAP> {
         let pad_after = 'compute_pad: {
             if let Some(pad) = &arg.pad {
                 // Check if the argument must be padded.
-                let non_padded_len = arg.inner.formatted_len();
-                if pad.width > non_padded_len.chars {
-                    let (pad_before, pad_after) = pad.compute_padding(non_padded_len.chars);
-                    let mut count = 0;
-                    while count < pad_before {
-                        self = self.write_char(pad.using);
-                        count += 1;
-                    }
+                let non_padded_len = arg.non_padded_len();
+                let non_padded_width = pad.measure(&non_padded_len);
+                if pad.width > non_padded_width {
+                    let (pad_before, pad_after) = pad.compute_padding(non_padded_width);
+                    self = self.write_pad(pad.using, pad_before);
                     break 'compute_pad Some((pad_after, pad.using));
                 }
             }
             None
         };
 
+        let affixes = arg.output_affixes();
+        self = self.write_str_bytes(affixes.prefix.as_bytes());
         self = match arg.inner {
             ArgumentInner::Str(s, fmt) => self.write_str(s, fmt),
-            // chars and ints are not affected by format so far (i.e., not clipped)
-            ArgumentInner::Char(c) => self.write_char(c),
+            ArgumentInner::Char(c, None) => self.write_char(c),
+            ArgumentInner::Char(c, Some(fmt)) => {
+                let encoded = CompileArgs::<4>::new().write_char(c);
+                self.write_str(encoded.as_str(), Some(fmt))
+            }
+            // ints are not affected by format so far (i.e., not clipped)
             ArgumentInner::Int(value) => self.write_i128(value),
             ArgumentInner::UnsignedInt(value) => self.write_u128(value),
         };
+        self = self.write_str_bytes(affixes.suffix.as_bytes());
         if let Some((pad_after, using)) = pad_after {
-            let mut count = 0;
-            while count < pad_after {
-                self = self.write_char(using);
-                count += 1;
-            }
+            self = self.write_pad(using, pad_after);
         }
         self
     }
+
+    const fn write_pad(mut self, using: PadFill, char_count: usize) -> Self {
+        match using {
+            PadFill::Char(c) => {
+                let mut count = 0;
+                while count < char_count {
+                    self = self.write_char(c);
+                    count += 1;
+                }
+                self
+            }
+            PadFill::Pattern(pattern) => {
+                let pattern_chars = count_chars(pattern);
+                let mut count = 0;
+                while count < char_count / pattern_chars {
+                    self = self.write_str_bytes(pattern.as_bytes());
+                    count += 1;
+                }
+                match ClippedStr::new(pattern, char_count % pattern_chars) {
+                    ClippedStr::Full(bytes) | ClippedStr::Clipped(bytes) => {
+                        self.write_str_bytes(bytes)
+                    }
+                }
+            }
+        }
+    }
 }
 
 /// ASCII string wrapper.
@@ -181,11 +285,100 @@ impl<'a> Ascii<'a> {
     }
 }
 
+/// [`CompileArgs`] consisting entirely of ASCII chars, usually created
+/// with the [`ascii_args!`](crate::ascii_args) macro.
+///
+/// Similar to [`Ascii`] strings, this allows decreasing capacity requirements when nesting
+/// formatted args: each char in `AsciiArgs` is known to occupy exactly 1 byte, while in the general
+/// case, each char is assumed to require up to 4 bytes.
+///
+/// `AsciiArgs` dereference to [`CompileArgs`] and thus provide all their methods.
+///
+/// # Examples
+///
+/// ```
+/// use compile_fmt::{ascii_args, compile_args, fmt, AsciiArgs, CompileArgs};
+///
+/// type CodeArgs = AsciiArgs<10>;
+///
+/// const fn format_code(code: u16) -> CodeArgs {
+///     ascii_args!("code=", code => fmt::<u16>())
+/// }
+///
+/// let code = format_code(42);
+/// let s: CompileArgs<16> = compile_args!(
+///     "[", &code => fmt::<&CodeArgs>().pad_right(14, ' '), "]"
+/// );
+/// assert_eq!(s.as_str(), "[       code=42]");
+/// // ^ For `&CompileArgs<10>`, the required capacity would be 42 bytes.
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiArgs<const CAP: usize>(CompileArgs<CAP>);
+
+impl<const CAP: usize> fmt::Display for AsciiArgs<CAP> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.0.as_str())
+    }
+}
+
+impl<const CAP: usize> AsRef<str> for AsciiArgs<CAP> {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl<const CAP: usize> ops::Deref for AsciiArgs<CAP> {
+    type Target = CompileArgs<CAP>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const CAP: usize> AsciiArgs<CAP> {
+    /// Wraps the provided args if they consist entirely of ASCII chars.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the args contain non-ASCII chars.
+    pub const fn new(args: CompileArgs<CAP>) -> Self {
+        assert_is_ascii(args.as_str());
+        Self(args)
+    }
+
+    #[doc(hidden)] // implementation detail of the `ascii_args` macro
+    pub const fn new_unchecked(args: CompileArgs<CAP>) -> Self {
+        Self(args)
+    }
+
+    /// Returns the `str` value of these args.
+    pub const fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Unwraps the contained [`CompileArgs`].
+    pub const fn into_inner(self) -> CompileArgs<CAP> {
+        self.0
+    }
+}
+
+impl<const CAP: usize> Sealed for &AsciiArgs<CAP> {}
+
+impl<const CAP: usize> FormatArgument for &AsciiArgs<CAP> {
+    type Details = StrFormat;
+    const DEFAULT_DETAILS: StrFormat = StrFormat::UNCLIPPED;
+    const MAX_BYTES_PER_CHAR: usize = 1;
+}
+
+impl<const CAP: usize> MaxLength for &AsciiArgs<CAP> {
+    const MAX_LENGTH: StrLength = StrLength::both(CAP);
+}
+
 /// Wrapper for an admissible argument type allowing to convert it to an [`Argument`] in compile time.
 #[doc(hidden)] // implementation detail of crate macros
 pub struct ArgumentWrapper<T: FormatArgument> {
     value: T,
-    fmt: Option<Fmt<T>>,
+    pub(crate) fmt: Option<Fmt<T>>,
 }
 
 impl<T> fmt::Debug for ArgumentWrapper<T>
@@ -217,13 +410,11 @@ impl<T: FormatArgument> ArgumentWrapper<T> {
 impl<'a> ArgumentWrapper<&'a str> {
     /// Performs the conversion.
     pub const fn into_argument(self) -> Argument<'a> {
-        let (str_fmt, pad) = match self.fmt {
-            Some(Fmt { details, pad, .. }) => (Some(details), pad),
-            None => (None, None),
-        };
+        let (str_fmt, pad, affixes) = Fmt::into_parts(self.fmt);
         Argument {
             inner: ArgumentInner::Str(self.value, str_fmt),
             pad,
+            affixes,
         }
     }
 }
@@ -231,13 +422,11 @@ impl<'a> ArgumentWrapper<&'a str> {
 impl<'a> ArgumentWrapper<Ascii<'a>> {
     /// Performs the conversion.
     pub const fn into_argument(self) -> Argument<'a> {
-        let (str_fmt, pad) = match self.fmt {
-            Some(Fmt { details, pad, .. }) => (Some(details), pad),
-            None => (None, None),
-        };
+        let (str_fmt, pad, affixes) = Fmt::into_parts(self.fmt);
         Argument {
             inner: ArgumentInner::Str(self.value.0, str_fmt),
             pad,
+            affixes,
         }
     }
 }
@@ -245,112 +434,178 @@ impl<'a> ArgumentWrapper<Ascii<'a>> {
 impl<'a, const CAP: usize> ArgumentWrapper<&'a CompileArgs<CAP>> {
     /// Performs the conversion.
     pub const fn into_argument(self) -> Argument<'a> {
+        let (str_fmt, pad, affixes) = Fmt::into_parts(self.fmt);
         Argument {
-            inner: ArgumentInner::Str(self.value.as_str(), None),
-            pad: None,
+            inner: ArgumentInner::Str(self.value.as_str(), str_fmt),
+            pad,
+            affixes,
         }
     }
 }
 
-impl ArgumentWrapper<i128> {
+impl<'a, const CAP: usize> ArgumentWrapper<&'a AsciiArgs<CAP>> {
     /// Performs the conversion.
-    pub const fn into_argument(self) -> Argument<'static> {
-        let pad = match self.fmt {
-            Some(Fmt { pad, .. }) => pad,
-            None => None,
-        };
+    pub const fn into_argument(self) -> Argument<'a> {
+        let (str_fmt, pad, affixes) = Fmt::into_parts(self.fmt);
         Argument {
-            inner: ArgumentInner::Int(self.value),
+            inner: ArgumentInner::Str(self.value.as_str(), str_fmt),
             pad,
+            affixes,
         }
     }
 }
 
-macro_rules! impl_argument_wrapper_for_int {
-    ($int:ty) => {
-        impl ArgumentWrapper<$int> {
-            /// Performs the conversion.
-            pub const fn into_argument(self) -> Argument<'static> {
-                let pad = match self.fmt {
-                    Some(Fmt { pad, .. }) => pad,
-                    None => None,
-                };
-                Argument {
-                    inner: ArgumentInner::Int(self.value as i128),
-                    pad,
-                }
-            }
+/// Signed or unsigned integer type.
+#[doc(hidden)] // implementation detail of crate macros
+pub trait Integer: FormatArgument + Copy {
+    /// Whether the type is signed.
+    const SIGNED: bool;
+}
+
+macro_rules! impl_integer {
+    ($signed:expr => $($int:ty),+) => {
+        $(
+        impl Integer for $int {
+            const SIGNED: bool = $signed;
         }
+        )+
     };
 }
 
-impl_argument_wrapper_for_int!(i8);
-impl_argument_wrapper_for_int!(i16);
-impl_argument_wrapper_for_int!(i32);
-impl_argument_wrapper_for_int!(i64);
-impl_argument_wrapper_for_int!(isize);
+impl_integer!(false => u8, u16, u32, u64, u128, usize);
+impl_integer!(true => i8, i16, i32, i64, i128, isize);
+
+#[repr(C)]
+union IntegerBytes<T: Copy> {
+    value: T,
+    bytes: [u8; 16],
+}
+
+/// Converts an integer to `u128`, sign-extending signed integers. Integer conversions cannot be
+/// performed generically in compile time otherwise.
+const fn integer_to_u128<T: Integer>(value: T) -> u128 {
+    let size = mem::size_of::<T>();
+    assert!(size <= 16, "Integer type is too large");
+
+    let mut union = IntegerBytes { bytes: [0; 16] };
+    union.value = value;
+    // SAFETY: all bytes are initialized; the first `size` bytes are copied from `value`
+    // (integers do not have padding), and the remaining bytes are zeroed.
+    let bytes = unsafe { union.bytes };
+
+    let is_negative = T::SIGNED && {
+        let most_significant_byte = if cfg!(target_endian = "little") {
+            bytes[size - 1]
+        } else {
+            bytes[0]
+        };
+        most_significant_byte >= 0x80
+    };
+    let mut le_bytes = [if is_negative { 0xff } else { 0 }; 16];
+    let mut i = 0;
+    while i < size {
+        le_bytes[i] = if cfg!(target_endian = "little") {
+            bytes[i]
+        } else {
+            bytes[size - 1 - i]
+        };
+        i += 1;
+    }
+    u128::from_le_bytes(le_bytes)
+}
 
-impl ArgumentWrapper<u128> {
+/// A single implementation for all integer types allows unsuffixed integer literals
+/// to fall back to `i32`, as in the rest of Rust.
+impl<T: Integer> ArgumentWrapper<T> {
     /// Performs the conversion.
+    #[allow(clippy::cast_possible_wrap)] // intentional; `value` is sign-extended
     pub const fn into_argument(self) -> Argument<'static> {
-        let pad = match self.fmt {
-            Some(Fmt { pad, .. }) => pad,
-            None => None,
+        let (_, pad, affixes) = Fmt::into_parts(self.fmt);
+        let value = integer_to_u128(self.value);
+        let inner = if T::SIGNED {
+            ArgumentInner::Int(value as i128)
+        } else {
+            ArgumentInner::UnsignedInt(value)
         };
         Argument {
-            inner: ArgumentInner::UnsignedInt(self.value),
+            inner,
             pad,
+            affixes,
         }
     }
 }
 
-macro_rules! impl_argument_wrapper_for_uint {
-    ($uint:ty) => {
-        impl ArgumentWrapper<$uint> {
-            /// Performs the conversion.
-            pub const fn into_argument(self) -> Argument<'static> {
-                let pad = match self.fmt {
-                    Some(Fmt { pad, .. }) => pad,
-                    None => None,
-                };
-                Argument {
-                    inner: ArgumentInner::UnsignedInt(self.value as u128),
-                    pad,
-                }
-            }
+impl ArgumentWrapper<char> {
+    /// Performs the conversion.
+    pub const fn into_argument(self) -> Argument<'static> {
+        let (char_fmt, pad, affixes) = Fmt::into_parts(self.fmt);
+        Argument {
+            inner: ArgumentInner::Char(self.value, char_fmt),
+            pad,
+            affixes,
         }
-    };
+    }
 }
 
-impl_argument_wrapper_for_uint!(u8);
-impl_argument_wrapper_for_uint!(u16);
-impl_argument_wrapper_for_uint!(u32);
-impl_argument_wrapper_for_uint!(u64);
-impl_argument_wrapper_for_uint!(usize);
-
-impl ArgumentWrapper<char> {
+impl ArgumentWrapper<&char> {
     /// Performs the conversion.
     pub const fn into_argument(self) -> Argument<'static> {
-        let pad = match self.fmt {
-            Some(Fmt { pad, .. }) => pad,
-            None => None,
-        };
+        let (char_fmt, pad, affixes) = Fmt::into_parts(self.fmt);
         Argument {
-            inner: ArgumentInner::Char(self.value),
+            inner: ArgumentInner::Char(*self.value, char_fmt),
             pad,
+            affixes,
         }
     }
 }
 
+macro_rules! impl_argument_wrapper_for_ref {
+    ($($ty:ty),+) => {
+        $(
+        impl ArgumentWrapper<&$ty> {
+            /// Performs the conversion.
+            pub const fn into_argument(self) -> Argument<'static> {
+                let (_, pad, affixes) = Fmt::into_parts(self.fmt);
+                let mut argument = ArgumentWrapper::new(*self.value).into_argument();
+                argument.pad = pad;
+                argument.affixes = affixes;
+                argument
+            }
+        }
+        )+
+    };
+}
+
+impl_argument_wrapper_for_ref!(u8, u16, u32, u64, u128, usize);
+impl_argument_wrapper_for_ref!(i8, i16, i32, i64, i128, isize);
+
 #[cfg(test)]
 mod tests {
     use rand::{rngs::StdRng, Rng, SeedableRng};
 
     use super::*;
+    use crate::{clip, clip_bytes, clip_start, elide_middle, format::CenterBias};
     use core::fmt::Alignment;
     use rand::distr::uniform::{UniformSampler, UniformUsize};
     use std::string::ToString;
 
+    #[test]
+    fn converting_integers_to_u128() {
+        assert_eq!(integer_to_u128(0_u8), 0);
+        assert_eq!(integer_to_u128(u8::MAX), 255);
+        assert_eq!(integer_to_u128(-1_i8), u128::MAX);
+        assert_eq!(integer_to_u128(i8::MIN).cast_signed(), -128);
+        assert_eq!(integer_to_u128(i16::MIN).cast_signed(), i16::MIN.into());
+        assert_eq!(integer_to_u128(i64::MIN).cast_signed(), i64::MIN.into());
+        assert_eq!(integer_to_u128(u64::MAX), u64::MAX.into());
+        assert_eq!(
+            integer_to_u128(isize::MIN).cast_signed(),
+            i128::try_from(isize::MIN).unwrap()
+        );
+        assert_eq!(integer_to_u128(i128::MIN).cast_signed(), i128::MIN);
+        assert_eq!(integer_to_u128(u128::MAX), u128::MAX);
+    }
+
     #[test]
     fn length_estimation_for_small_ints() {
         for i in 0_u8..=u8::MAX {
@@ -461,77 +716,69 @@ mod tests {
 
     #[test]
     fn formatted_len_for_clipped_strings() {
-        let arg = ArgumentInner::Str(
-            "teßt",
-            Some(StrFormat {
-                clip_at: 2,
-                using: "",
-            }),
-        );
+        let arg = ArgumentInner::Str("teßt", Some(clip(2, "").details));
         assert_eq!(arg.formatted_len(), StrLength::for_str("te"));
 
-        let arg = ArgumentInner::Str(
-            "teßt",
-            Some(StrFormat {
-                clip_at: 2,
-                using: "...",
-            }),
-        );
+        let arg = ArgumentInner::Str("teßt", Some(clip(2, "...").details));
         assert_eq!(arg.formatted_len(), StrLength::for_str("te..."));
 
-        let arg = ArgumentInner::Str(
-            "teßt",
-            Some(StrFormat {
-                clip_at: 2,
-                using: "…",
-            }),
-        );
+        let arg = ArgumentInner::Str("teßt", Some(clip(2, "…").details));
         assert_eq!(arg.formatted_len(), StrLength::for_str("te…"));
 
-        let arg = ArgumentInner::Str(
-            "teßt",
-            Some(StrFormat {
-                clip_at: 3,
-                using: "",
-            }),
-        );
+        let arg = ArgumentInner::Str("teßt", Some(clip(3, "").details));
         assert_eq!(arg.formatted_len(), StrLength::for_str("teß"));
 
-        let arg = ArgumentInner::Str(
-            "teßt",
-            Some(StrFormat {
-                clip_at: 3,
-                using: "…",
-            }),
-        );
+        let arg = ArgumentInner::Str("teßt", Some(clip(3, "…").details));
         assert_eq!(arg.formatted_len(), StrLength::for_str("teß…"));
 
-        let arg = ArgumentInner::Str(
-            "teßt",
-            Some(StrFormat {
-                clip_at: 3,
-                using: "-",
-            }),
-        );
+        let arg = ArgumentInner::Str("teßt", Some(clip(3, "-").details));
         assert_eq!(arg.formatted_len(), StrLength::for_str("teß-"));
 
         for clip_at in [4, 5, 16] {
             for using in ["", "...", "…"] {
-                let arg = ArgumentInner::Str("teßt", Some(StrFormat { clip_at, using }));
+                let arg = ArgumentInner::Str("teßt", Some(clip(clip_at, using).details));
                 assert_eq!(arg.formatted_len(), StrLength::for_str("teßt"));
             }
         }
     }
 
+    #[test]
+    fn formatted_len_for_strings_clipped_at_start_or_middle() {
+        let arg = ArgumentInner::Str("teßt", Some(clip_start(2, "…").details));
+        assert_eq!(arg.formatted_len(), StrLength::for_str("…ßt"));
+        let arg = ArgumentInner::Str("teßt", Some(elide_middle(1, 2, "…").details));
+        assert_eq!(arg.formatted_len(), StrLength::for_str("t…ßt"));
+        let arg = ArgumentInner::Str("teßt", Some(elide_middle(2, 2, "…").details));
+        assert_eq!(arg.formatted_len(), StrLength::for_str("teßt"));
+    }
+
+    #[test]
+    fn formatted_len_for_strings_with_omitted_count() {
+        let fmt = clip(2, "…").with_omitted_count().details;
+        let arg = ArgumentInner::Str("teßt", Some(fmt));
+        assert_eq!(arg.formatted_len(), StrLength::for_str("te… (+2 chars)"));
+        let arg = ArgumentInner::Str("te", Some(fmt));
+        assert_eq!(arg.formatted_len(), StrLength::for_str("te"));
+
+        let fmt = clip_bytes(2, "").with_omitted_count().details;
+        let arg = ArgumentInner::Str("teßt", Some(fmt));
+        assert_eq!(arg.formatted_len(), StrLength::for_str("te (+3 bytes)"));
+    }
+
     #[test]
     fn formatted_len_with_padding() {
         let argument = Argument {
             inner: ArgumentInner::Str("teßt", None),
             pad: Some(Pad {
                 align: Alignment::Left,
+                center_bias: CenterBias::ExtraOnRight,
                 width: 8,
-                using: ' ',
+                max_width: 8,
+                using: PadFill::Char(' '),
+                in_columns: false,
+                is_dynamic: false,
             }),
+            affixes: Affixes::NONE,
         };
         assert_eq!(argument.formatted_len(), "teßt    ".len());
 
@@ -539,9 +786,14 @@ mod tests {
             inner: ArgumentInner::Str("teßt", None),
             pad: Some(Pad {
                 align: Alignment::Left,
+                center_bias: CenterBias::ExtraOnRight,
                 width: 8,
-                using: '💣',
+                max_width: 8,
+                using: PadFill::Char('💣'),
+                in_columns: false,
+                is_dynamic: false,
             }),
+            affixes: Affixes::NONE,
         };
         assert_eq!(argument.formatted_len(), "teßt💣💣💣💣".len());
 
@@ -550,9 +802,14 @@ mod tests {
                 inner: ArgumentInner::Str("teßt", None),
                 pad: Some(Pad {
                     align: Alignment::Left,
+                    center_bias: CenterBias::ExtraOnRight,
                     width: pad_width,
-                    using: ' ',
+                    max_width: pad_width,
+                    using: PadFill::Char(' '),
+                    in_columns: false,
+                    is_dynamic: false,
                 }),
+                affixes: Affixes::NONE,
             };
             assert_eq!(argument.formatted_len(), "teßt".len());
         }
@@ -560,20 +817,19 @@ mod tests {
 
     #[test]
     fn formatted_len_with_padding_and_clipping() {
-        let inner = ArgumentInner::Str(
-            "teßt",
-            Some(StrFormat {
-                clip_at: 3,
-                using: "…",
-            }),
-        );
+        let inner = ArgumentInner::Str("teßt", Some(clip(3, "…").details));
         let argument = Argument {
             inner,
             pad: Some(Pad {
                 align: Alignment::Left,
+                center_bias: CenterBias::ExtraOnRight,
                 width: 8,
-                using: ' ',
+                max_width: 8,
+                using: PadFill::Char(' '),
+                in_columns: false,
+                is_dynamic: false,
             }),
+            affixes: Affixes::NONE,
         };
         assert_eq!(argument.formatted_len(), "teß…    ".len());
 
@@ -581,9 +837,14 @@ mod tests {
             inner,
             pad: Some(Pad {
                 align: Alignment::Left,
+                center_bias: CenterBias::ExtraOnRight,
                 width: 8,
-                using: '💣',
+                max_width: 8,
+                using: PadFill::Char('💣'),
+                in_columns: false,
+                is_dynamic: false,
             }),
+            affixes: Affixes::NONE,
         };
         assert_eq!(argument.formatted_len(), "teß…💣💣💣💣".len());
 
@@ -592,17 +853,77 @@ mod tests {
                 inner,
                 pad: Some(Pad {
                     align: Alignment::Left,
+                    center_bias: CenterBias::ExtraOnRight,
                     width: pad_width,
-                    using: ' ',
+                    max_width: pad_width,
+                    using: PadFill::Char(' '),
+                    in_columns: false,
+                    is_dynamic: false,
                 }),
+                affixes: Affixes::NONE,
             };
             assert_eq!(argument.formatted_len(), "teß…".len());
         }
     }
 
+    #[test]
+    fn formatted_len_with_padding_in_columns() {
+        let argument = Argument {
+            inner: ArgumentInner::Str("日本", None),
+            pad: Some(Pad {
+                align: Alignment::Left,
+                center_bias: CenterBias::ExtraOnRight,
+                width: 8,
+                max_width: 8,
+                using: PadFill::Char(' '),
+                in_columns: true,
+                is_dynamic: false,
+            }),
+            affixes: Affixes::NONE,
+        };
+        assert_eq!(argument.formatted_len(), "日本    ".len());
+
+        let argument = Argument {
+            inner: ArgumentInner::Str("e\u{301}", None),
+            pad: Some(Pad {
+                align: Alignment::Left,
+                center_bias: CenterBias::ExtraOnRight,
+                width: 2,
+                max_width: 2,
+                using: PadFill::Char('-'),
+                in_columns: true,
+                is_dynamic: false,
+            }),
+            affixes: Affixes::NONE,
+        };
+        assert_eq!(argument.formatted_len(), "e\u{301}-".len());
+    }
+
     #[test]
     #[should_panic(expected = "String 'teß…' contains non-ASCII chars; first at position 2")]
     fn ascii_panic() {
         Ascii::new("teß…");
     }
+
+    #[test]
+    fn checking_argument_is_ascii() {
+        assert!(ArgumentWrapper::new("test").into_argument().is_ascii());
+        assert!(!ArgumentWrapper::new("teß").into_argument().is_ascii());
+        assert!(ArgumentWrapper::new(-42_i32).into_argument().is_ascii());
+        assert!(ArgumentWrapper::new('!').into_argument().is_ascii());
+        assert!(!ArgumentWrapper::new('ß').into_argument().is_ascii());
+
+        let argument = ArgumentWrapper::new("teß")
+            .with_fmt(crate::clip(2, ""))
+            .into_argument();
+        assert!(argument.is_ascii());
+        let argument = ArgumentWrapper::new("teß")
+            .with_fmt(crate::clip(2, "…"))
+            .into_argument();
+        assert!(!argument.is_ascii());
+        let argument = ArgumentWrapper::new(42_u8)
+            .with_fmt(crate::fmt::<u8>().pad_left(4, 'ß'))
+            .into_argument();
+        assert!(!argument.is_ascii());
+    }
 }
@@ -1,24 +1,42 @@
 //! `Fmt` and related types.
 
-use crate::argument::Ascii;
+use crate::argument::{log_10_ceil, Ascii};
 use core::fmt::Alignment;
 
-use crate::utils::{assert_is_ascii, count_chars};
+use crate::{
+    logfmt,
+    percent::PercentEncodeSet,
+    transform::{count_mapped_chars, CharMap, CharMaps, StrSink},
+    unicode::{char_columns, count_columns, MAX_GRAPHEME_CHARS},
+    utils::{
+        assert_is_ascii, clip_at_word_boundary, count_chars, count_chars_in_bytes, is_ascii,
+        last_chars, max_char_len, ClippedStr,
+    },
+};
 
-/// Length of a string measured in bytes and chars.
+/// Length of a string measured in bytes, chars and displayed columns.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct StrLength {
     /// Number of bytes the string occupies.
     pub bytes: usize,
     /// Number of chars in the string.
     pub chars: usize,
+    /// Number of columns the string occupies when displayed; see [`Self::columns()`].
+    pub(crate) columns: usize,
 }
 
 impl StrLength {
+    /// Creates an upper boundary for a string length with the specified number of bytes and chars.
+    /// The number of displayed columns is bounded by `bytes`.
+    pub const fn new(bytes: usize, chars: usize) -> Self {
+        Self::upper_bound(bytes, chars)
+    }
+
     pub(crate) const fn for_str(s: &str) -> Self {
         Self {
             bytes: s.len(),
             chars: count_chars(s),
+            columns: count_columns(s.as_bytes()),
         }
     }
 
@@ -26,36 +44,173 @@ impl StrLength {
         Self {
             bytes: c.len_utf8(),
             chars: 1,
+            columns: char_columns(c as u32),
+        }
+    }
+
+    /// Sums two lengths. Saturates on overflow, so that unbounded lengths stay unbounded.
+    pub(crate) const fn sum(self, other: Self) -> Self {
+        Self {
+            bytes: self.bytes.saturating_add(other.bytes),
+            chars: self.chars.saturating_add(other.chars),
+            columns: self.columns.saturating_add(other.columns),
+        }
+    }
+
+    /// Creates an upper boundary for a string length with the specified number of bytes and chars.
+    const fn upper_bound(bytes: usize, chars: usize) -> Self {
+        Self {
+            bytes,
+            chars,
+            columns: bytes,
         }
     }
 
-    /// Creates a length in which both `bytes` and `chars` fields are set to the specified `value`.
+    /// Creates a length in which bytes, chars and displayed columns are set to the specified `value`.
     pub const fn both(value: usize) -> Self {
         Self {
             bytes: value,
             chars: value,
+            columns: value,
+        }
+    }
+
+    /// Returns the number of columns the string occupies when displayed, assuming a monospace font.
+    /// Wide chars (e.g., CJK ideographs and most emoji) occupy 2 columns, and combining chars occupy
+    /// 0 columns. Since a wide char is encoded with at least 3 bytes, this never exceeds `bytes`.
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+}
+
+/// Static text attached before and after a formatted value.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Affixes {
+    pub prefix: &'static str,
+    pub suffix: &'static str,
+    /// Whether affixes are omitted for empty values (empty strings and zero integers).
+    pub skip_if_empty: bool,
+}
+
+impl Affixes {
+    pub const NONE: Self = Self {
+        prefix: "",
+        suffix: "",
+        skip_if_empty: false,
+    };
+
+    pub const fn len(&self) -> StrLength {
+        StrLength::for_str(self.prefix).sum(StrLength::for_str(self.suffix))
+    }
+
+    pub const fn is_ascii(&self) -> bool {
+        is_ascii(self.prefix.as_bytes()) && is_ascii(self.suffix.as_bytes())
+    }
+}
+
+/// Char or string pattern used for padding.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PadFill {
+    Char(char),
+    /// Non-empty string pattern repeated as necessary; the last repetition may be clipped.
+    Pattern(&'static str),
+}
+
+impl PadFill {
+    /// Returns the byte length of the padding with the specified number of chars.
+    pub const fn byte_len(&self, char_count: usize) -> usize {
+        match self {
+            Self::Char(c) => c.len_utf8() * char_count,
+            Self::Pattern(pattern) => {
+                let pattern_chars = count_chars(pattern);
+                let remainder = match ClippedStr::new(pattern, char_count % pattern_chars) {
+                    ClippedStr::Full(bytes) | ClippedStr::Clipped(bytes) => bytes.len(),
+                };
+                (char_count / pattern_chars) * pattern.len() + remainder
+            }
+        }
+    }
+
+    /// Returns the maximum byte length of a single padding char.
+    pub const fn max_char_len(&self) -> usize {
+        match self {
+            Self::Char(c) => c.len_utf8(),
+            Self::Pattern(pattern) => max_char_len(pattern),
+        }
+    }
+
+    pub const fn is_ascii(&self) -> bool {
+        match self {
+            Self::Char(c) => c.is_ascii(),
+            Self::Pattern(pattern) => is_ascii(pattern.as_bytes()),
         }
     }
 }
 
+/// Bias of center-aligned padding, i.e., where to put the extra padding char if the padding
+/// cannot be split evenly between its left and right parts.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::{compile_args, fmt, CenterBias};
+/// let s = compile_args!("[", 42 => fmt::<i32>().pad_center(5, '_'), "]");
+/// assert_eq!(s.as_str(), "[_42__]");
+/// // ^ Same as `format!("[{:_^5}]", 42)`
+/// let s = compile_args!(
+///     "[", 42 => fmt::<i32>().pad_center_with(5, '_', CenterBias::ExtraOnLeft), "]"
+/// );
+/// assert_eq!(s.as_str(), "[__42_]");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CenterBias {
+    /// Put the extra padding char on the right, i.e., shift the value to the left.
+    /// This is the default, which matches the `std` behavior for the `{:^}` format.
+    #[default]
+    ExtraOnRight,
+    /// Put the extra padding char on the left, i.e., shift the value to the right.
+    ExtraOnLeft,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Pad {
     pub align: Alignment,
+    /// Only used with `Alignment::Center`.
+    pub center_bias: CenterBias,
     pub width: usize,
-    pub using: char,
+    /// Maximum padding width used to compute capacity. Usually equal to `width`.
+    pub max_width: usize,
+    pub using: PadFill,
+    /// Whether `width` is measured in displayed columns rather than chars.
+    pub in_columns: bool,
+    /// Whether `width` can be overridden for a specific argument (only for `pad_*_dyn()` formats).
+    pub is_dynamic: bool,
 }
 
 impl Pad {
-    pub const fn compute_padding(&self, char_count: usize) -> (usize, usize) {
-        if char_count >= self.width {
+    /// Returns the width of a string with the specified length in the units used by this padding.
+    pub const fn measure(&self, len: &StrLength) -> usize {
+        if self.in_columns {
+            len.columns
+        } else {
+            len.chars
+        }
+    }
+
+    pub const fn compute_padding(&self, width: usize) -> (usize, usize) {
+        if width >= self.width {
             return (0, 0);
         }
         match self.align {
-            Alignment::Left => (0, self.width - char_count),
-            Alignment::Right => (self.width - char_count, 0),
+            Alignment::Left => (0, self.width - width),
+            Alignment::Right => (self.width - width, 0),
             Alignment::Center => {
-                let total_padding = self.width - char_count;
-                (total_padding / 2, total_padding - total_padding / 2)
+                let total_padding = self.width - width;
+                let smaller_part = total_padding / 2;
+                match self.center_bias {
+                    CenterBias::ExtraOnRight => (smaller_part, total_padding - smaller_part),
+                    CenterBias::ExtraOnLeft => (total_padding - smaller_part, smaller_part),
+                }
             }
         }
     }
@@ -65,8 +220,8 @@ impl Pad {
 ///
 /// A format is necessary to specify for *dynamic* arguments of [`compile_args!`](crate::compile_args)
 /// and related macros (i.e., for arguments that are not constants). For now, the only meaningful
-/// format customization is provided for strings (`&str`). All other arguments have the only
-/// available format that can be created using [`fmt()`].
+/// format customization is provided for strings (`&str`) and nested [`CompileArgs`](crate::CompileArgs). All other arguments
+/// have the only available format that can be created using [`fmt()`]. Any format can be padded.
 ///
 /// # Examples
 ///
@@ -136,17 +291,71 @@ pub struct Fmt<T: FormatArgument> {
     capacity: StrLength,
     pub(crate) details: T::Details,
     pub(crate) pad: Option<Pad>,
+    pub(crate) affixes: Affixes,
 }
 
 /// Creates a default format for a type that has known bounded formatting width.
 pub const fn fmt<T>() -> Fmt<T>
 where
-    T: FormatArgument<Details = ()> + MaxLength,
+    T: FormatArgument + MaxLength,
 {
     Fmt {
         capacity: T::MAX_LENGTH,
-        details: (),
+        details: T::DEFAULT_DETAILS,
         pad: None,
+        affixes: Affixes::NONE,
+    }
+}
+
+/// Creates a format for strings without any transforms. Since the length of strings is not bounded,
+/// the format must be clipped (e.g., using [`Fmt::clip()`]) in order to be used; other transforms
+/// can be specified before clipping.
+///
+/// Transforms of a string format (char maps such as [`Fmt::to_ascii_uppercase()`], and clipping)
+/// are applied in the order they are specified. At most one clipping transform is allowed.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::{compile_args, str_fmt};
+/// const fn format_method(method: &str) -> impl AsRef<str> {
+///     compile_args!("method: ", method => str_fmt().to_ascii_uppercase().clip(7, "…"))
+/// }
+///
+/// assert_eq!(format_method("get").as_ref(), "method: GET");
+/// assert_eq!(format_method("propfind").as_ref(), "method: PROPFIN…");
+/// ```
+pub const fn str_fmt<'a>() -> Fmt<&'a str> {
+    unbounded()
+}
+
+/// Creates a format that will [percent-encode](Fmt::percent_encode()) chars in the specified set.
+///
+/// The format is unbounded; it must be clipped (e.g., using [`Fmt::clip()`]) to be used
+/// in [`compile_args!`](crate::compile_args). Clipping is performed on the encoded string.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::{compile_args, percent_encode, PercentEncodeSet};
+/// const fn query(value: &str) -> impl AsRef<str> {
+///     compile_args!("?q=", value => percent_encode(PercentEncodeSet::QUERY).clip(8, ""))
+/// }
+///
+/// assert_eq!(query("a&b").as_ref(), "?q=a%26b");
+/// // `%XX` triplets are never split by clipping
+/// assert_eq!(query("ab cd ef").as_ref(), "?q=ab%20cd");
+/// ```
+pub const fn percent_encode<'a>(set: PercentEncodeSet) -> Fmt<&'a str> {
+    str_fmt().percent_encode(set)
+}
+
+pub(crate) const fn unbounded<T: FormatArgument<Details = StrFormat>>() -> Fmt<T> {
+    Fmt {
+        capacity: StrLength::both(usize::MAX),
+        details: StrFormat::UNCLIPPED,
+        pad: None,
+        affixes: Affixes::NONE,
     }
 }
 
@@ -158,14 +367,152 @@ where
 /// Panics if `clip_at` is zero.
 pub const fn clip<'a>(clip_at: usize, using: &'static str) -> Fmt<&'a str> {
     assert!(clip_at > 0, "Clip width must be positive");
-    Fmt {
-        capacity: StrLength {
-            bytes: clip_at * char::MAX_LENGTH.bytes + using.len(),
-            chars: clip_at + count_chars(using),
-        },
-        details: StrFormat { clip_at, using },
-        pad: None,
-    }
+    unbounded().with_clip(ClipFormat::end(clip_at, using))
+}
+
+/// Creates a format that will clip the value to the specified max **char** width, keeping the end
+/// of the string. If clipped, the start of the string will be replaced with the specified replacer,
+/// which can be empty. This is useful for strings where the tail is more important than the head,
+/// such as file paths.
+///
+/// # Panics
+///
+/// Panics if `clip_at` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::{compile_args, clip_start};
+/// const fn format_path(path: &str) -> impl AsRef<str> {
+///     compile_args!("file: ", path => clip_start(10, "…"))
+/// }
+///
+/// assert_eq!(format_path("crates/compile-fmt/src/lib.rs").as_ref(), "file: …src/lib.rs");
+/// assert_eq!(format_path("src/lib.rs").as_ref(), "file: src/lib.rs");
+/// ```
+pub const fn clip_start<'a>(clip_at: usize, using: &'static str) -> Fmt<&'a str> {
+    assert!(clip_at > 0, "Clip width must be positive");
+    unbounded().with_clip(ClipFormat::start(clip_at, using))
+}
+
+/// Creates a format that will elide the middle of the value if it's longer than `head + tail` chars.
+/// In this case, the first `head` and last `tail` chars will be retained, and the elided part
+/// will be replaced with the specified replacer, which can be empty.
+///
+/// # Panics
+///
+/// Panics if both `head` and `tail` are zero.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::{compile_args, elide_middle};
+/// const fn format_hash(hash: &str) -> impl AsRef<str> {
+///     compile_args!("hash: ", hash => elide_middle(6, 4, "…"))
+/// }
+///
+/// assert_eq!(format_hash("0x1234567890abcdef").as_ref(), "hash: 0x1234…cdef");
+/// assert_eq!(format_hash("0x1234").as_ref(), "hash: 0x1234");
+/// ```
+pub const fn elide_middle<'a>(head: usize, tail: usize, using: &'static str) -> Fmt<&'a str> {
+    assert!(head + tail > 0, "Clip width must be positive");
+    unbounded().with_clip(ClipFormat::middle(head, tail, using))
+}
+
+/// Creates a format that will clip the value to the specified max **char** width similar to [`clip()`],
+/// but will try to avoid cutting words. That is, the clipped string is shortened to the last word
+/// boundary (ASCII whitespace or after ASCII punctuation) within `clip_at` chars, with trailing
+/// whitespace trimmed. If there is no such boundary, the string is clipped at `clip_at` chars.
+///
+/// The capacity of this format is the same as for [`clip()`].
+///
+/// # Panics
+///
+/// Panics if `clip_at` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::{compile_args, clip_words};
+/// const fn format_message(message: &str) -> impl AsRef<str> {
+///     compile_args!("error: ", message => clip_words(16, "…"))
+/// }
+///
+/// let s = format_message("invalid value, expected a string");
+/// assert_eq!(s.as_ref(), "error: invalid value,…");
+/// let s = format_message("unsupported operation");
+/// assert_eq!(s.as_ref(), "error: unsupported…");
+/// // If the word is too long, the string is clipped in the middle of the word.
+/// let s = format_message("antidisestablishmentarianism");
+/// assert_eq!(s.as_ref(), "error: antidisestablish…");
+/// ```
+pub const fn clip_words<'a>(clip_at: usize, using: &'static str) -> Fmt<&'a str> {
+    assert!(clip_at > 0, "Clip width must be positive");
+    unbounded().with_clip(ClipFormat::end_in(ClipUnit::Words, clip_at, using))
+}
+
+/// Creates a format that will clip the value to the specified number of grapheme clusters (i.e.,
+/// user-perceived chars) rather than chars. Unlike [`clip()`], this format never separates combining marks
+/// from their base char and does not cut emoji sequences (e.g., flags or ZWJ sequences). If clipped, the end
+/// of the string will be replaced with the specified replacer, which can be empty.
+///
+/// Grapheme clusters are determined as [extended grapheme clusters] (including Indic conjuncts and emoji
+/// ZWJ sequences) using tables generated from the Unicode Character Database. A single cluster is limited to 8 chars; longer clusters are split. Correspondingly, the capacity
+/// of this format is `clip_at * 32 + using.len()` bytes.
+///
+/// [extended grapheme clusters]: https://www.unicode.org/reports/tr29/
+///
+/// # Panics
+///
+/// Panics if `clip_at` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::{compile_args, clip, clip_graphemes};
+/// const NAME: &str = "Zoe\u{308} 🇺🇸";
+/// let s = compile_args!("name: ", NAME => clip_graphemes(3, "…"));
+/// assert_eq!(s.as_str(), "name: Zoe\u{308}…");
+/// // Compare with char-based clipping:
+/// let s = compile_args!("name: ", NAME => clip(3, "…"));
+/// assert_eq!(s.as_str(), "name: Zoe…");
+///
+/// let s = compile_args!("name: ", NAME => clip_graphemes(5, "…"));
+/// assert_eq!(s.as_str(), "name: Zoe\u{308} 🇺🇸");
+/// ```
+pub const fn clip_graphemes<'a>(clip_at: usize, using: &'static str) -> Fmt<&'a str> {
+    assert!(clip_at > 0, "Clip width must be positive");
+    unbounded().with_clip(ClipFormat::end_in(ClipUnit::Graphemes, clip_at, using))
+}
+
+/// Creates a format that will clip the value to the specified max **byte** length, retaining as many
+/// whole chars as fit. If clipped, the end of the string will be replaced with the specified replacer,
+/// which can be empty.
+///
+/// Unlike [`clip()`], the capacity of this format does not need to account for chars occupying
+/// up to 4 bytes; it is exactly `max_bytes + using.len()`. Thus, this format is more space-efficient
+/// for mostly ASCII strings.
+///
+/// # Panics
+///
+/// Panics if `max_bytes` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::{compile_args, clip_bytes, CompileArgs};
+/// const fn format_str(s: &str) -> CompileArgs<17> {
+///     compile_args!("string: ", s => clip_bytes(6, "…"))
+/// }
+///
+/// assert_eq!(format_str("test").as_str(), "string: test");
+/// assert_eq!(format_str("long string").as_str(), "string: long s…");
+/// // Chars are never split
+/// assert_eq!(format_str("tℝℝℝ").as_str(), "string: tℝ…");
+/// ```
+pub const fn clip_bytes<'a>(max_bytes: usize, using: &'static str) -> Fmt<&'a str> {
+    assert!(max_bytes > 0, "Clip width must be positive");
+    unbounded().with_clip(ClipFormat::end_in(ClipUnit::Bytes, max_bytes, using))
 }
 
 /// Same as [`clip()`], but for [`Ascii`] strings.
@@ -175,20 +522,84 @@ pub const fn clip<'a>(clip_at: usize, using: &'static str) -> Fmt<&'a str> {
 /// Panics if `clip_at` is zero or `using` contains non-ASCII chars.
 pub const fn clip_ascii<'a>(clip_at: usize, using: &'static str) -> Fmt<Ascii<'a>> {
     assert!(clip_at > 0, "Clip width must be positive");
-    assert_is_ascii(using);
-    Fmt {
-        capacity: StrLength::both(clip_at + using.len()),
-        details: StrFormat { clip_at, using },
-        pad: None,
-    }
+    unbounded().with_clip(ClipFormat::end(clip_at, using))
+}
+
+/// Same as [`clip_start()`], but for [`Ascii`] strings.
+///
+/// # Panics
+///
+/// Panics if `clip_at` is zero or `using` contains non-ASCII chars.
+pub const fn clip_start_ascii<'a>(clip_at: usize, using: &'static str) -> Fmt<Ascii<'a>> {
+    assert!(clip_at > 0, "Clip width must be positive");
+    unbounded().with_clip(ClipFormat::start(clip_at, using))
+}
+
+/// Same as [`elide_middle()`], but for [`Ascii`] strings.
+///
+/// # Panics
+///
+/// Panics if both `head` and `tail` are zero, or `using` contains non-ASCII chars.
+pub const fn elide_middle_ascii<'a>(
+    head: usize,
+    tail: usize,
+    using: &'static str,
+) -> Fmt<Ascii<'a>> {
+    assert!(head + tail > 0, "Clip width must be positive");
+    unbounded().with_clip(ClipFormat::middle(head, tail, using))
 }
 
 impl<T: FormatArgument> Fmt<T> {
-    const fn pad(mut self, align: Alignment, width: usize, using: char) -> Self {
+    const fn pad(self, align: Alignment, width: usize, using: char) -> Self {
+        self.pad_with_fill(align, width, PadFill::Char(using))
+    }
+
+    const fn pad_with_fill(mut self, align: Alignment, width: usize, using: PadFill) -> Self {
         let pad = Pad {
             align,
+            center_bias: CenterBias::ExtraOnRight,
             width,
+            max_width: width,
             using,
+            in_columns: false,
+            is_dynamic: false,
+        };
+        self.pad = Some(pad);
+        self
+    }
+
+    const fn pad_pattern(self, align: Alignment, width: usize, pattern: &'static str) -> Self {
+        assert!(!pattern.is_empty(), "Padding pattern must be non-empty");
+        self.pad_with_fill(align, width, PadFill::Pattern(pattern))
+    }
+
+    const fn pad_columns(mut self, align: Alignment, width: usize, using: char) -> Self {
+        assert!(
+            char_columns(using as u32) == 1,
+            "Padding char must occupy exactly 1 column"
+        );
+        let pad = Pad {
+            align,
+            center_bias: CenterBias::ExtraOnRight,
+            width,
+            max_width: width,
+            using: PadFill::Char(using),
+            in_columns: true,
+            is_dynamic: false,
+        };
+        self.pad = Some(pad);
+        self
+    }
+
+    const fn pad_dyn(mut self, align: Alignment, max_width: usize, using: char) -> Self {
+        let pad = Pad {
+            align,
+            center_bias: CenterBias::ExtraOnRight,
+            width: max_width,
+            max_width,
+            using: PadFill::Char(using),
+            in_columns: false,
+            is_dynamic: true,
         };
         self.pad = Some(pad);
         self
@@ -207,26 +618,340 @@ impl<T: FormatArgument> Fmt<T> {
     }
 
     /// Specifies center-aligned padding. `width` is measured in chars, rather than bytes.
+    /// If the padding cannot be split evenly, the extra padding char is put on the right,
+    /// same as for the `{:^}` format in `std`; use [`Self::pad_center_with()`] to change this.
     #[must_use]
     pub const fn pad_center(self, width: usize, using: char) -> Self {
         self.pad(Alignment::Center, width, using)
     }
 
+    /// Specifies center-aligned padding with the specified bias. `width` is measured in chars,
+    /// rather than bytes. This is a shortcut for `pad_center(width, using).center_bias(bias)`.
+    #[must_use]
+    pub const fn pad_center_with(self, width: usize, using: char, bias: CenterBias) -> Self {
+        self.pad_center(width, using).center_bias(bias)
+    }
+
+    /// Sets the bias of the previously specified center-aligned padding (e.g., one created
+    /// with [`Self::pad_center_columns()`] or [`Self::pad_center_dyn()`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format has no center-aligned padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip, CenterBias};
+    /// let s = compile_args!(
+    ///     "[", "日本" => clip(2, "").pad_center_columns(7, '_').center_bias(CenterBias::ExtraOnLeft), "]"
+    /// );
+    /// assert_eq!(s.as_str(), "[__日本_]");
+    /// ```
+    #[must_use]
+    pub const fn center_bias(mut self, bias: CenterBias) -> Self {
+        let Some(mut pad) = self.pad else {
+            panic!("Center bias can only be specified for center-aligned padding");
+        };
+        assert!(
+            matches!(pad.align, Alignment::Center),
+            "Center bias can only be specified for center-aligned padding"
+        );
+        pad.center_bias = bias;
+        self.pad = Some(pad);
+        self
+    }
+
+    /// Specifies left-aligned padding with the width not necessarily known in compile time (e.g.,
+    /// computed from data in a `const fn`). The capacity of the format is computed based on `max_width`.
+    /// The actual width is specified after the format as `$arg => $fmt; $width` in [`compile_args!`]
+    /// and related macros; if it's not specified, it's equal to `max_width`. Like in [`Self::pad_left()`],
+    /// widths are measured in chars.
+    ///
+    /// Providing the width exceeding `max_width` will result in a panic. Specifying the width
+    /// for formats other than `pad_*_dyn()` is a compile-time error.
+    ///
+    /// [`compile_args!`]: crate::compile_args
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip};
+    /// const MAX_KEY_LEN: usize = 16;
+    ///
+    /// const fn max_len(keys: &[&str]) -> usize {
+    ///     let mut max_len = 0;
+    ///     let mut i = 0;
+    ///     while i < keys.len() {
+    ///         if keys[i].len() > max_len {
+    ///             max_len = keys[i].len();
+    ///         }
+    ///         i += 1;
+    ///     }
+    ///     max_len
+    /// }
+    ///
+    /// const fn format_key(key: &str, keys: &[&str]) -> impl AsRef<str> {
+    ///     let width = max_len(keys);
+    ///     compile_args!(
+    ///         key => clip(MAX_KEY_LEN, "").pad_left_dyn(MAX_KEY_LEN, ' '); width, " = "
+    ///     )
+    /// }
+    ///
+    /// const KEYS: &[&str] = &["id", "name", "timestamp"];
+    /// assert_eq!(format_key("id", KEYS).as_ref(), "id        = ");
+    /// assert_eq!(format_key("timestamp", KEYS).as_ref(), "timestamp = ");
+    /// ```
+    #[must_use]
+    pub const fn pad_left_dyn(self, max_width: usize, using: char) -> Self {
+        self.pad_dyn(Alignment::Left, max_width, using)
+    }
+
+    /// Specifies right-aligned padding with the width not necessarily known in compile time.
+    /// See [`Self::pad_left_dyn()`] for details.
+    #[must_use]
+    pub const fn pad_right_dyn(self, max_width: usize, using: char) -> Self {
+        self.pad_dyn(Alignment::Right, max_width, using)
+    }
+
+    /// Specifies center-aligned padding with the width not necessarily known in compile time.
+    /// See [`Self::pad_left_dyn()`] for details.
+    #[must_use]
+    pub const fn pad_center_dyn(self, max_width: usize, using: char) -> Self {
+        self.pad_dyn(Alignment::Center, max_width, using)
+    }
+
+    /// Specifies left-aligned padding with a repeating string pattern. `width` is measured in chars,
+    /// rather than bytes. The last repetition of the pattern is clipped if necessary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip, fmt};
+    /// const fn format_entry(key: &str, value: u32) -> impl AsRef<str> {
+    ///     compile_args!(
+    ///         key => clip(16, "").pad_left_pattern(16, ". "),
+    ///         value => fmt::<u32>().pad_right(4, ' ')
+    ///     )
+    /// }
+    ///
+    /// assert_eq!(format_entry("width", 42).as_ref(), "width. . . . . .  42");
+    /// assert_eq!(format_entry("height", 1024).as_ref(), "height. . . . . 1024");
+    /// ```
+    #[must_use]
+    pub const fn pad_left_pattern(self, width: usize, pattern: &'static str) -> Self {
+        self.pad_pattern(Alignment::Left, width, pattern)
+    }
+
+    /// Specifies right-aligned padding with a repeating string pattern.
+    /// See [`Self::pad_left_pattern()`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is empty.
+    #[must_use]
+    pub const fn pad_right_pattern(self, width: usize, pattern: &'static str) -> Self {
+        self.pad_pattern(Alignment::Right, width, pattern)
+    }
+
+    /// Specifies center-aligned padding with a repeating string pattern. Padding on each side
+    /// starts with the start of the pattern. See [`Self::pad_left_pattern()`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip};
+    /// let s = compile_args!(" Title " => clip(8, "").pad_center_pattern(19, "=-"));
+    /// assert_eq!(s.as_str(), "=-=-=- Title =-=-=-");
+    /// ```
+    #[must_use]
+    pub const fn pad_center_pattern(self, width: usize, pattern: &'static str) -> Self {
+        self.pad_pattern(Alignment::Center, width, pattern)
+    }
+
+    /// Specifies left-aligned padding. Unlike [`Self::pad_left()`], `width` is measured in displayed columns
+    /// rather than chars, i.e., wide chars (e.g., CJK ideographs and most emoji) are considered to occupy
+    /// 2 columns, and combining chars are considered to occupy 0 columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `using` does not occupy exactly 1 column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip};
+    /// const fn format_cell(s: &str) -> impl AsRef<str> {
+    ///     compile_args!("|", s => clip(8, "").pad_left_columns(8, ' '), "|")
+    /// }
+    ///
+    /// assert_eq!(format_cell("test").as_ref(), "|test    |");
+    /// assert_eq!(format_cell("日本語").as_ref(), "|日本語  |");
+    /// assert_eq!(format_cell("Zoe\u{308}").as_ref(), "|Zoe\u{308}     |");
+    /// ```
+    #[must_use]
+    pub const fn pad_left_columns(self, width: usize, using: char) -> Self {
+        self.pad_columns(Alignment::Left, width, using)
+    }
+
+    /// Specifies right-aligned padding with `width` measured in displayed columns.
+    /// See [`Self::pad_left_columns()`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `using` does not occupy exactly 1 column.
+    #[must_use]
+    pub const fn pad_right_columns(self, width: usize, using: char) -> Self {
+        self.pad_columns(Alignment::Right, width, using)
+    }
+
+    /// Specifies center-aligned padding with `width` measured in displayed columns.
+    /// See [`Self::pad_left_columns()`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `using` does not occupy exactly 1 column.
+    #[must_use]
+    pub const fn pad_center_columns(self, width: usize, using: char) -> Self {
+        self.pad_columns(Alignment::Center, width, using)
+    }
+
+    /// Attaches static text before the formatted value. The prefix is considered a part of the value
+    /// for padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, fmt};
+    /// const fn format_duration(ms: u32) -> impl AsRef<str> {
+    ///     compile_args!(
+    ///         "[", ms => fmt::<u32>().prefix("~").suffix(" ms").pad_right(8, ' '), "]"
+    ///     )
+    /// }
+    ///
+    /// assert_eq!(format_duration(42).as_ref(), "[  ~42 ms]");
+    /// ```
+    #[must_use]
+    pub const fn prefix(mut self, prefix: &'static str) -> Self {
+        self.affixes.prefix = prefix;
+        self
+    }
+
+    /// Attaches static text after the formatted value. The suffix is considered a part of the value
+    /// for padding.
+    #[must_use]
+    pub const fn suffix(mut self, suffix: &'static str) -> Self {
+        self.affixes.suffix = suffix;
+        self
+    }
+
+    /// Omits the [prefix](Self::prefix()) and [suffix](Self::suffix()) if the value is empty.
+    /// Empty values are empty strings (including nested arguments) and zero integers;
+    /// the value itself is still output (i.e., zeros are output as `0`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, fmt, clip};
+    /// const fn format_timing(ms: u32, details: &str) -> impl AsRef<str> {
+    ///     compile_args!(
+    ///         "took ",
+    ///         ms => fmt::<u32>().suffix(" ms").skip_affixes_if_empty(),
+    ///         details => clip(16, "…").prefix(": ").skip_affixes_if_empty()
+    ///     )
+    /// }
+    ///
+    /// assert_eq!(format_timing(42, "slow").as_ref(), "took 42 ms: slow");
+    /// assert_eq!(format_timing(0, "").as_ref(), "took 0");
+    /// ```
+    #[must_use]
+    pub const fn skip_affixes_if_empty(mut self) -> Self {
+        self.affixes.skip_if_empty = true;
+        self
+    }
+
+    /// Splits an optional format into parts used by [`Argument`](crate::Argument)s.
+    // `Option<&Self>` cannot be used: borrowing generic values is not allowed in `const fn`s on MSRV
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) const fn into_parts(
+        fmt: Option<Self>,
+    ) -> (Option<T::Details>, Option<Pad>, Affixes) {
+        match fmt {
+            Some(Self {
+                details,
+                pad,
+                affixes,
+                ..
+            }) => (Some(details), pad, affixes),
+            None => (None, None, Affixes::NONE),
+        }
+    }
+
+    /// Asserts that this format produces only ASCII chars.
+    #[doc(hidden)] // only used by macros
+    pub const fn assert_ascii(&self) {
+        assert!(
+            T::MAX_BYTES_PER_CHAR == 1,
+            "Argument format is not provably ASCII; use integers, `Ascii` strings or `AsciiArgs`"
+        );
+        if let Some(pad) = &self.pad {
+            assert!(pad.using.is_ascii(), "Padding is not ASCII");
+        }
+        assert!(self.affixes.is_ascii(), "Prefix or suffix is not ASCII");
+    }
+
+    /// Asserts that this format has dynamic padding, i.e., its padding width can be specified
+    /// as `$arg => $fmt; $width`.
+    #[doc(hidden)] // only used by macros
+    pub const fn assert_dynamic_pad(&self) {
+        let is_dynamic = matches!(&self.pad, Some(pad) if pad.is_dynamic);
+        assert!(
+            is_dynamic,
+            "Padding width can only be specified for formats with dynamic padding (e.g., `pad_left_dyn()`)"
+        );
+    }
+
     /// Returns the byte capacity of this format in bytes.
     #[doc(hidden)] // only used by macros
     pub const fn capacity(&self) -> usize {
+        assert!(
+            self.capacity.bytes < usize::MAX,
+            "String format is unbounded; specify clipping, e.g. using `Fmt::clip()`"
+        );
+        let capacity = self.capacity.sum(self.affixes.len());
         if let Some(pad) = &self.pad {
+            if pad.in_columns {
+                // A string may consist entirely of zero-width chars, so we cannot bound the padding
+                // from the string length.
+                return pad.using.max_char_len() * pad.max_width + capacity.bytes;
+            }
+
             // Capacity necessary for an empty non-padded string (which we assume is always possible).
-            let full_pad_capacity = pad.using.len_utf8() * pad.width;
+            let full_pad_capacity = pad.using.max_char_len() * pad.max_width;
 
-            let max_width = if self.capacity.chars > pad.width {
-                pad.width
+            let max_width = if capacity.chars > pad.max_width {
+                pad.max_width
             } else {
-                self.capacity.chars
+                capacity.chars
             };
+            let max_bytes_per_char = max(
+                T::MAX_BYTES_PER_CHAR,
+                max(
+                    max_char_len(self.affixes.prefix),
+                    max_char_len(self.affixes.suffix),
+                ),
+            );
             // Capacity necessary for the maximum-length string that still has padding.
-            let min_pad_capacity =
-                pad.using.len_utf8() * (pad.width - max_width) + max_width * T::MAX_BYTES_PER_CHAR;
+            let min_pad_capacity = pad.using.max_char_len() * (pad.max_width - max_width)
+                + max_width * max_bytes_per_char;
 
             // Select maximum of `max_pad_capacity`, `min_pad_capacity` and the original capacity.
             let pad_capacity = if full_pad_capacity > min_pad_capacity {
@@ -234,41 +959,686 @@ impl<T: FormatArgument> Fmt<T> {
             } else {
                 min_pad_capacity
             };
-            if pad_capacity > self.capacity.bytes {
+            if pad_capacity > capacity.bytes {
                 return pad_capacity;
             }
         }
-        self.capacity.bytes
+        capacity.bytes
+    }
+}
+
+impl<T: FormatArgument<Details = StrFormat>> Fmt<T> {
+    const fn with_clip(mut self, mut clip: ClipFormat) -> Self {
+        assert!(
+            !self.details.clip.is_clipping(),
+            "String format can contain at most one clipping transform"
+        );
+        if T::MAX_BYTES_PER_CHAR == 1 {
+            assert_is_ascii(clip.using);
+        }
+        let input_len = self.unquoted_capacity();
+        clip.max_omitted = match clip.unit {
+            ClipUnit::Bytes => input_len.bytes,
+            ClipUnit::Chars | ClipUnit::Words | ClipUnit::Graphemes => input_len.chars,
+        };
+        let using_len = StrSink::measure()
+            .push_bytes_twice(
+                clip.using.as_bytes(),
+                &self.details.pre_clip.escaping(),
+                &CharMaps::EMPTY,
+            )
+            .len();
+        let capacity = clip.bound(input_len, T::MAX_BYTES_PER_CHAR).sum(using_len);
+        self.capacity = capacity.sum(self.quotes_len());
+        self.details.clip = clip;
+        self
+    }
+
+    const fn with_char_map(mut self, map: CharMap) -> Self {
+        assert!(
+            self.details.quoting.is_none(),
+            "Char transforms cannot follow quoting transforms (`debug()` or `json_str()`)"
+        );
+        let capacity = CharMaps::EMPTY.push(map).bound(self.unquoted_capacity());
+        self.capacity = capacity.sum(self.quotes_len());
+        if self.details.clip.is_clipping() {
+            self.details.post_clip = self.details.post_clip.push(map);
+        } else {
+            self.details.pre_clip = self.details.pre_clip.push(map);
+        }
+        self
+    }
+
+    /// Returns the length of quotes surrounding the string.
+    const fn quotes_len(&self) -> StrLength {
+        match self.details.quoting {
+            Some(quoting) => StrLength::both(2 * quoting.quote().len_utf8()),
+            None => StrLength::both(0),
+        }
+    }
+
+    /// Returns the capacity of the string without quotes, which are not affected by transforms.
+    const fn unquoted_capacity(&self) -> StrLength {
+        if self.capacity.bytes == usize::MAX {
+            return self.capacity; // the capacity is unbounded
+        }
+        let quotes_len = self.quotes_len();
+        StrLength {
+            bytes: self.capacity.bytes - quotes_len.bytes,
+            chars: self.capacity.chars - quotes_len.chars,
+            columns: self.capacity.columns - quotes_len.columns,
+        }
+    }
+
+    /// Specifies clipping similar to [`clip()`] as the next transform of the string. The capacity
+    /// of the format takes into account the previously specified transforms; e.g., for nested
+    /// [`CompileArgs`](crate::CompileArgs), the capacity takes `CAP` into account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `clip_at` is zero, the format already clips strings, or if the format is ASCII-only
+    /// (e.g., for [`Ascii`] strings or nested [`AsciiArgs`](crate::AsciiArgs)) and `using` contains
+    /// non-ASCII chars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, fmt, CompileArgs};
+    /// type ErrorArgs = CompileArgs<32>;
+    ///
+    /// const fn format_error(message: &ErrorArgs) -> impl AsRef<str> {
+    ///     compile_args!(
+    ///         "[", message => fmt::<&ErrorArgs>().clip(8, "…").pad_left(9, ' '), "]"
+    ///     )
+    /// }
+    ///
+    /// let message = compile_args!(capacity: 32, "failed with ", 42_u32);
+    /// assert_eq!(format_error(&message).as_ref(), "[failed w…]");
+    /// let message = compile_args!(capacity: 32, "failed");
+    /// assert_eq!(format_error(&message).as_ref(), "[failed   ]");
+    /// ```
+    #[must_use]
+    pub const fn clip(self, clip_at: usize, using: &'static str) -> Self {
+        assert!(clip_at > 0, "Clip width must be positive");
+        self.with_clip(ClipFormat::end(clip_at, using))
+    }
+
+    /// Escapes the string similar to its `Debug` implementation as the next transform of the string,
+    /// and surrounds the result with quotes: double quotes for strings and single quotes for `char`s.
+    /// Quotes are not affected by subsequent transforms; e.g., they are never clipped.
+    ///
+    /// Escaping follows the standard library: `\t`, `\r`, `\n`, `\\`, `\0` and the quote char are escaped
+    /// with a backslash, and non-printable chars and combining marks are escaped as `\u{..}`.
+    /// Printable chars and combining marks are determined using tables generated from the Unicode
+    /// Character Database; unassigned chars are considered non-printable, as in the standard library.
+    ///
+    /// Escaping can increase the string length up to 10 times (6 times for ASCII strings), which is taken
+    /// into account in the format capacity. If escaping precedes clipping, clipping is performed
+    /// on the escaped string, but never cuts an escape sequence in half; the replacer and the omitted count
+    /// are escaped as well.
+    ///
+    /// No char transforms (e.g., [`Self::to_ascii_uppercase()`]) can follow escaping, since they could
+    /// break escape sequences.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format is already quoted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip, fmt, str_fmt};
+    /// const fn format_input(input: &str) -> impl AsRef<str> {
+    ///     compile_args!("input: ", input => clip(8, "…").debug())
+    /// }
+    ///
+    /// let s = format_input("line\n\"quoted\"");
+    /// assert_eq!(s.as_ref(), r#"input: "line\n\"qu…""#);
+    ///
+    /// // Escape first, then clip the escaped string.
+    /// let s = compile_args!("input: ", "line\n\"quoted\"" => str_fmt().debug().clip(8, "…"));
+    /// assert_eq!(s.as_str(), r#"input: "line\n\"…""#);
+    ///
+    /// let s = compile_args!("char: ", '\'' => fmt::<char>().debug());
+    /// assert_eq!(s.as_str(), r"char: '\''");
+    /// ```
+    #[must_use]
+    pub const fn debug(self) -> Self {
+        let quote = T::DEBUG_QUOTE;
+        self.with_quoting_map(CharMap::EscapeDebug { quote }, Quoting::Debug(quote))
+    }
+
+    /// Escapes the string as per [RFC 8259] as the next transform of the string, and surrounds
+    /// the result with double quotes, producing a valid JSON string. Quotes are not affected
+    /// by subsequent transforms; e.g., they are never clipped.
+    ///
+    /// `"`, `\` and control chars U+0000 to U+001F are escaped, using the short form (e.g., `\n`)
+    /// where available, and the `\u00..` form otherwise. Other chars (including non-ASCII ones)
+    /// are retained as is.
+    ///
+    /// Escaping can increase the string length up to 6 times, which is taken into account
+    /// in the format capacity. If escaping precedes clipping, clipping is performed on the escaped string,
+    /// but never cuts an escape sequence in half; the replacer and the omitted count are escaped as well.
+    /// No char transforms (e.g., [`Self::to_ascii_uppercase()`]) can follow escaping. Thus, the output
+    /// is always a valid JSON string.
+    ///
+    /// [RFC 8259]: https://www.rfc-editor.org/rfc/rfc8259#section-7
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format is already quoted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip, str_fmt};
+    /// const fn format_field(value: &str) -> impl AsRef<str> {
+    ///     compile_args!("{\"value\":", value => clip(16, "…").json_str(), "}")
+    /// }
+    ///
+    /// let s = format_field("C:\\Windows\n");
+    /// assert_eq!(s.as_ref(), r#"{"value":"C:\\Windows\n"}"#);
+    ///
+    /// // Escape first, then clip the escaped string.
+    /// let s = compile_args!("\"a\tb\"" => str_fmt().json_str().clip(4, "…"));
+    /// assert_eq!(s.as_str(), r#""\"a…""#);
+    /// ```
+    #[must_use]
+    pub const fn json_str(self) -> Self {
+        self.with_quoting_map(CharMap::EscapeJson, Quoting::Json)
+    }
+
+    /// [Percent-encodes] chars in the specified set (and all non-ASCII chars) as the next transform
+    /// of the string. Encoding can increase the string length up to 3 times, which is taken into account
+    /// in the format capacity. If encoding precedes clipping, clipping is performed on the encoded string,
+    /// but never cuts a `%XX` triplet or an encoded char in half; the replacer and the omitted count
+    /// are encoded as well.
+    ///
+    /// [Percent-encodes]: https://www.rfc-editor.org/rfc/rfc3986#section-2.1
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format is quoted (e.g., by [`Self::debug()`] or [`Self::json_str()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip, percent_encode, PercentEncodeSet};
+    /// const fn device_url(name: &str, token: &str) -> impl AsRef<str> {
+    ///     compile_args!(
+    ///         "https://example.com/devices/",
+    ///         name => clip(16, "").percent_encode(PercentEncodeSet::PATH_SEGMENT),
+    ///         "?token=",
+    ///         token => percent_encode(PercentEncodeSet::QUERY).clip(24, "")
+    ///     )
+    /// }
+    ///
+    /// let url = device_url("sensor #1", "a+b=c");
+    /// assert_eq!(
+    ///     url.as_ref(),
+    ///     "https://example.com/devices/sensor%20%231?token=a%2Bb%3Dc"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn percent_encode(self, set: PercentEncodeSet) -> Self {
+        self.with_char_map(CharMap::PercentEncode(set))
+    }
+
+    pub(crate) const fn with_quoting_map(self, map: CharMap, quoting: Quoting) -> Self {
+        assert!(
+            self.details.quoting.is_none(),
+            "String format is already quoted"
+        );
+        let mut this = self.with_char_map(map);
+        this.details.quoting = Some(quoting);
+        this.capacity = this.capacity.sum(this.quotes_len());
+        this
+    }
+
+    /// Converts ASCII chars in the string to uppercase as the next transform of the string.
+    /// Non-ASCII chars are left as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format is quoted (e.g., by [`Self::debug()`] or [`Self::json_str()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip, str_fmt};
+    /// const fn format_level(level: &str) -> impl AsRef<str> {
+    ///     compile_args!("[", level => clip(4, "").to_ascii_uppercase(), "]")
+    /// }
+    ///
+    /// assert_eq!(format_level("warn").as_ref(), "[WARN]");
+    /// assert_eq!(format_level("error").as_ref(), "[ERRO]");
+    ///
+    /// // Transforms are applied in order, so the replacer is transformed as well:
+    /// let s = compile_args!("[", "error" => clip(3, "_e").to_ascii_uppercase(), "]");
+    /// assert_eq!(s.as_str(), "[ERR_E]");
+    /// let s = compile_args!("[", "error" => str_fmt().to_ascii_uppercase().clip(3, "_e"), "]");
+    /// assert_eq!(s.as_str(), "[ERR_e]");
+    /// ```
+    #[must_use]
+    pub const fn to_ascii_uppercase(self) -> Self {
+        self.with_char_map(CharMap::AsciiUppercase)
+    }
+
+    /// Converts ASCII chars in the string to lowercase as the next transform of the string.
+    /// Non-ASCII chars are left as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format is quoted (e.g., by [`Self::debug()`] or [`Self::json_str()`]).
+    #[must_use]
+    pub const fn to_ascii_lowercase(self) -> Self {
+        self.with_char_map(CharMap::AsciiLowercase)
+    }
+
+    /// Appends the number of omitted chars to a clipped string, e.g. `very long str… (+57 chars)`.
+    /// If the string is clipped by [`clip_bytes()`], the number of omitted bytes is appended instead.
+    /// Must be called after specifying clipping; the capacity of the format is increased
+    /// to accommodate the maximum possible count. The number of digits in the count is derived
+    /// from the maximum length of the formatted value (e.g., for nested [`CompileArgs`](crate::CompileArgs)),
+    /// or is the maximum number of digits in `usize` if the length is not bounded.
+    ///
+    /// If char transforms precede clipping (e.g., [`Self::json_str()`] or [`Self::percent_encode()`]),
+    /// the count refers to chars in the original string rather than in the transformed one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format does not clip strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip};
+    /// const fn format_input(input: &str) -> impl AsRef<str> {
+    ///     compile_args!(
+    ///         "input is too large: ", input => clip(8, "…").with_omitted_count()
+    ///     )
+    /// }
+    ///
+    /// let s = format_input("very long input string");
+    /// assert_eq!(s.as_ref(), "input is too large: very lon… (+14 chars)");
+    /// let s = format_input("short");
+    /// assert_eq!(s.as_ref(), "input is too large: short");
+    /// ```
+    #[must_use]
+    pub const fn with_omitted_count(mut self) -> Self {
+        assert!(
+            self.details.clip.is_clipping(),
+            "Omitted count can only be specified for clipping formats"
+        );
+        self.details.clip.count_omitted = true;
+        // The omitted count is transformed by escaping pre-clip maps and post-clip maps,
+        // so we need to account for this.
+        let omitted_len = self
+            .details
+            .clip
+            .max_omitted_len(&self.details.pre_clip.escaping());
+        let omitted_len = self.details.post_clip.bound(omitted_len);
+        self.capacity = self.capacity.sum(omitted_len);
+        self
+    }
+}
+
+const fn min(x: usize, y: usize) -> usize {
+    if x < y {
+        x
+    } else {
+        y
+    }
+}
+
+const fn max(x: usize, y: usize) -> usize {
+    if x > y {
+        x
+    } else {
+        y
     }
 }
 
 /// Type that can be formatted. Implemented for standard integer types, `&str` and `char`.
-pub trait FormatArgument {
+///
+/// This trait is sealed; it cannot be implemented outside of this crate.
+pub trait FormatArgument: private::Sealed {
     /// Formatting specification for the type.
     type Details: 'static + Copy;
+    /// Default formatting specification used by [`fmt()`].
+    #[doc(hidden)] // implementation detail
+    const DEFAULT_DETAILS: Self::Details;
     /// Maximum number of bytes a single char from this format can occupy.
     #[doc(hidden)] // implementation detail
     const MAX_BYTES_PER_CHAR: usize;
+    /// Quote char used by [`Fmt::debug()`].
+    #[doc(hidden)] // implementation detail
+    const DEBUG_QUOTE: char = '"';
+}
+
+pub(crate) mod private {
+    /// Seals [`FormatArgument`](super::FormatArgument).
+    pub trait Sealed {}
 }
 
+impl private::Sealed for &str {}
+
 impl FormatArgument for &str {
     type Details = StrFormat;
+    const DEFAULT_DETAILS: StrFormat = StrFormat::UNCLIPPED;
     const MAX_BYTES_PER_CHAR: usize = 4;
 }
 
+impl private::Sealed for Ascii<'_> {}
+
 impl FormatArgument for Ascii<'_> {
     type Details = StrFormat;
+    const DEFAULT_DETAILS: StrFormat = StrFormat::UNCLIPPED;
     const MAX_BYTES_PER_CHAR: usize = 1;
 }
 
-/// Formatting details for strings.
+/// References to fixed-width types (e.g., `&u32`) can be formatted in the same way as the referenced values.
+impl<T: FormatArgument<Details = ()>> private::Sealed for &T {}
+
+impl<T: FormatArgument<Details = ()>> FormatArgument for &T {
+    type Details = ();
+    const DEFAULT_DETAILS: () = ();
+    const MAX_BYTES_PER_CHAR: usize = T::MAX_BYTES_PER_CHAR;
+}
+
+/// Formatting details for strings: an ordered chain of transforms consisting of char maps applied
+/// before clipping, clipping, and char maps applied after clipping.
 #[doc(hidden)] // implementation detail
 #[derive(Debug, Clone, Copy)]
 pub struct StrFormat {
+    pub(crate) pre_clip: CharMaps,
+    pub(crate) clip: ClipFormat,
+    /// Char maps applied after clipping. These maps also apply to the replacer and the omitted count.
+    pub(crate) post_clip: CharMaps,
+    /// Quoting of the transformed string.
+    pub(crate) quoting: Option<Quoting>,
+}
+
+/// Quoting transform of a string, which determines the quote char and the escaping of the quoted string.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Quoting {
+    /// `Debug`-like escaping with the specified quote char, as in [`Fmt::debug()`].
+    Debug(char),
+    /// JSON string escaping, as in [`Fmt::json_str()`].
+    Json,
+    /// JSON string escaping with quotes only added if the string is not a valid unquoted logfmt value.
+    JsonIfNeeded,
+}
+
+impl Quoting {
+    const fn quote(self) -> char {
+        match self {
+            Self::Debug(quote) => quote,
+            Self::Json | Self::JsonIfNeeded => '"',
+        }
+    }
+}
+
+impl StrFormat {
+    /// Format that never transforms the string.
+    pub(crate) const UNCLIPPED: Self = Self::clipped(ClipFormat::UNCLIPPED);
+
+    const fn clipped(clip: ClipFormat) -> Self {
+        Self {
+            pre_clip: CharMaps::EMPTY,
+            clip,
+            post_clip: CharMaps::EMPTY,
+            quoting: None,
+        }
+    }
+
+    /// Applies this format to the string and pushes the result to the sink.
+    pub(crate) const fn apply<const CAP: usize>(
+        &self,
+        s: &str,
+        sink: StrSink<CAP>,
+    ) -> StrSink<CAP> {
+        let quoting = match self.quoting {
+            Some(Quoting::JsonIfNeeded) if !self.needs_quotes(s) => None,
+            quoting => quoting,
+        };
+        match quoting {
+            Some(quoting) => self
+                .apply_unquoted(s, sink.push_char(quoting.quote()))
+                .push_char(quoting.quote()),
+            None => self.apply_unquoted(s, sink),
+        }
+    }
+
+    /// Checks whether the formatted string needs quoting as a logfmt value. This is conservative
+    /// w.r.t. clipping; e.g., a space in the clipped part of the string still leads to quoting.
+    const fn needs_quotes(&self, s: &str) -> bool {
+        s.is_empty()
+            || self.clip.count_omitted // the omitted count contains spaces
+            || logfmt::needs_quotes(s.as_bytes())
+            || logfmt::needs_quotes(self.clip.using.as_bytes())
+    }
+
+    const fn apply_unquoted<const CAP: usize>(&self, s: &str, sink: StrSink<CAP>) -> StrSink<CAP> {
+        let clip = &self.clip;
+        let post = &self.post_clip;
+        if !self.pre_clip.is_empty() {
+            return self.apply_with_pre_clip_maps(s, sink);
+        }
+
+        match clip.clip(s) {
+            None => sink.push_bytes(s.as_bytes(), post),
+            Some((head, tail)) => {
+                let sink = sink
+                    .push_bytes(head, post)
+                    .push_bytes(clip.using.as_bytes(), post)
+                    .push_bytes(tail, post);
+                match clip.omitted(s, head, tail) {
+                    Some(count) => clip.push_omitted(count, sink, &CharMaps::EMPTY, post),
+                    None => sink,
+                }
+            }
+        }
+    }
+
+    /// Clips a string transformed by char maps. Since there's no buffer for the transformed string,
+    /// we cannot use [`ClipFormat::clip()`], but that's fine since the only clipping unit
+    /// that can follow char maps is [`ClipUnit::Chars`].
+    const fn apply_with_pre_clip_maps<const CAP: usize>(
+        &self,
+        s: &str,
+        sink: StrSink<CAP>,
+    ) -> StrSink<CAP> {
+        let clip = &self.clip;
+        let post = &self.post_clip;
+        let char_count = count_mapped_chars(s, &self.pre_clip);
+        let retained_chars = clip.clip_at.saturating_add(clip.tail_chars);
+        let range = if char_count <= retained_chars {
+            (char_count, char_count) // no clipping
+        } else {
+            (clip.clip_at, char_count - clip.tail_chars)
+        };
+        let (sink, replaced_chars) = sink.push_clipped(s, &self.pre_clip, range, clip.using, post);
+        if clip.count_omitted && replaced_chars > 0 {
+            clip.push_omitted(replaced_chars, sink, &self.pre_clip.escaping(), post)
+        } else {
+            sink
+        }
+    }
+}
+
+/// Clipping part of [`StrFormat`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ClipFormat {
+    /// Number of chars (or bytes, depending on `unit`) retained at the start of a clipped string.
     pub(crate) clip_at: usize,
+    /// Number of chars retained at the end of a clipped string.
+    pub(crate) tail_chars: usize,
+    pub(crate) unit: ClipUnit,
     pub(crate) using: &'static str,
+    /// Whether to append the number of omitted chars / bytes to a clipped string.
+    pub(crate) count_omitted: bool,
+    /// Upper boundary for the number of omitted chars / bytes derived from the length of the input string.
+    max_omitted: usize,
 }
 
+/// Unit in which [`ClipFormat::clip_at`] is measured.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ClipUnit {
+    Chars,
+    Bytes,
+    /// Chars, with the clipping point moved back to the last word boundary if possible.
+    Words,
+    /// Grapheme clusters.
+    Graphemes,
+}
+
+impl ClipFormat {
+    /// Format that never clips the string.
+    const UNCLIPPED: Self = Self::end(usize::MAX, "");
+
+    const fn end(clip_at: usize, using: &'static str) -> Self {
+        Self::middle(clip_at, 0, using)
+    }
+
+    const fn start(clip_at: usize, using: &'static str) -> Self {
+        Self::middle(0, clip_at, using)
+    }
+
+    const fn middle(head: usize, tail: usize, using: &'static str) -> Self {
+        Self {
+            clip_at: head,
+            tail_chars: tail,
+            unit: ClipUnit::Chars,
+            using,
+            count_omitted: false,
+            max_omitted: usize::MAX,
+        }
+    }
+
+    /// Creates a format clipping the end of the string with the specified unit of `clip_at`.
+    const fn end_in(unit: ClipUnit, clip_at: usize, using: &'static str) -> Self {
+        Self {
+            clip_at,
+            tail_chars: 0,
+            unit,
+            using,
+            count_omitted: false,
+            max_omitted: usize::MAX,
+        }
+    }
+
+    /// Transforms an upper boundary for the length of the input string into the boundary for the clipped string
+    /// (not including the replacer and the omitted count).
+    const fn bound(&self, len: StrLength, max_bytes_per_char: usize) -> StrLength {
+        let max_chars = match self.unit {
+            ClipUnit::Chars | ClipUnit::Words => self.clip_at + self.tail_chars,
+            ClipUnit::Bytes => self.clip_at,
+            ClipUnit::Graphemes => self.clip_at * MAX_GRAPHEME_CHARS,
+        };
+        let max_bytes = match self.unit {
+            ClipUnit::Bytes => self.clip_at,
+            _ => max_chars * max_bytes_per_char,
+        };
+        StrLength::upper_bound(min(len.bytes, max_bytes), min(len.chars, max_chars))
+    }
+
+    /// Returns the retained head and tail bytes of the string, or `None` if the string is not clipped.
+    const fn clip<'a>(&self, s: &'a str) -> Option<(&'a [u8], &'a [u8])> {
+        match self.unit {
+            ClipUnit::Bytes => {
+                return match ClippedStr::with_byte_budget(s, self.clip_at) {
+                    ClippedStr::Full(_) => None,
+                    ClippedStr::Clipped(head) => Some((head, &[])),
+                };
+            }
+            ClipUnit::Words => {
+                return match ClippedStr::new(s, self.clip_at) {
+                    ClippedStr::Full(_) => None,
+                    ClippedStr::Clipped(head) => Some((clip_at_word_boundary(s, head.len()), &[])),
+                };
+            }
+            ClipUnit::Graphemes => {
+                return match ClippedStr::with_graphemes(s, self.clip_at) {
+                    ClippedStr::Full(_) => None,
+                    ClippedStr::Clipped(head) => Some((head, &[])),
+                };
+            }
+            ClipUnit::Chars => { /* handled below */ }
+        }
+
+        if let ClippedStr::Full(_) = ClippedStr::new(s, self.clip_at + self.tail_chars) {
+            return None;
+        }
+        let head = match ClippedStr::new(s, self.clip_at) {
+            ClippedStr::Full(bytes) | ClippedStr::Clipped(bytes) => bytes,
+        };
+        Some((head, last_chars(s, self.tail_chars)))
+    }
+
+    const fn is_clipping(&self) -> bool {
+        self.clip_at < usize::MAX
+    }
+
+    /// Returns the number of omitted chars or bytes (depending on `unit`) if it should be displayed
+    /// for a clipped string.
+    const fn omitted(&self, s: &str, head: &[u8], tail: &[u8]) -> Option<usize> {
+        if !self.count_omitted {
+            return None;
+        }
+        Some(match self.unit {
+            ClipUnit::Bytes => s.len() - head.len(),
+            ClipUnit::Chars | ClipUnit::Words | ClipUnit::Graphemes => {
+                count_chars(s) - count_chars_in_bytes(head) - count_chars_in_bytes(tail)
+            }
+        })
+    }
+
+    /// Returns the suffix written after the omitted count.
+    const fn omitted_suffix(&self, count: usize) -> &'static str {
+        match (self.unit, count) {
+            (ClipUnit::Bytes, 1) => " byte)",
+            (ClipUnit::Bytes, _) => OMITTED_BYTES_SUFFIX,
+            (_, 1) => " char)",
+            _ => OMITTED_CHARS_SUFFIX,
+        }
+    }
+
+    /// Returns the maximum length of the omitted count appended by [`Fmt::with_omitted_count()`]
+    /// and transformed by `pre` maps.
+    const fn max_omitted_len(&self, pre: &CharMaps) -> StrLength {
+        let suffix = match self.unit {
+            ClipUnit::Bytes => OMITTED_BYTES_SUFFIX,
+            ClipUnit::Chars | ClipUnit::Words | ClipUnit::Graphemes => OMITTED_CHARS_SUFFIX,
+        };
+        let digit_count = log_10_ceil(self.max_omitted as u128);
+        StrSink::measure()
+            .push_bytes_twice(OMITTED_PREFIX.as_bytes(), pre, &CharMaps::EMPTY)
+            .push_bytes_twice(suffix.as_bytes(), pre, &CharMaps::EMPTY)
+            .len()
+            .sum(StrLength::both(digit_count))
+    }
+
+    /// Pushes the omitted count transformed by `pre` maps and then by `post` maps to the sink.
+    const fn push_omitted<const CAP: usize>(
+        &self,
+        count: usize,
+        sink: StrSink<CAP>,
+        pre: &CharMaps,
+        post: &CharMaps,
+    ) -> StrSink<CAP> {
+        // Escaping maps never change digits, so `pre` maps can be skipped for them.
+        sink.push_bytes_twice(OMITTED_PREFIX.as_bytes(), pre, post)
+            .push_usize(count, post)
+            .push_bytes_twice(self.omitted_suffix(count).as_bytes(), pre, post)
+    }
+}
+
+/// Prefix of the omitted count appended by [`Fmt::with_omitted_count()`].
+const OMITTED_PREFIX: &str = " (+";
+const OMITTED_CHARS_SUFFIX: &str = " chars)";
+const OMITTED_BYTES_SUFFIX: &str = " bytes)";
+
 /// Type that has a known upper boundary for the formatted length.
 pub trait MaxLength {
     /// Upper boundary for the formatted length in bytes and chars.
@@ -284,8 +1654,11 @@ macro_rules! impl_max_width_for_uint {
             );
         }
 
+        impl private::Sealed for $uint {}
+
         impl FormatArgument for $uint {
             type Details = ();
+            const DEFAULT_DETAILS: () = ();
             const MAX_BYTES_PER_CHAR: usize = 1;
         }
         )+
@@ -303,8 +1676,11 @@ macro_rules! impl_max_width_for_int {
             );
         }
 
+        impl private::Sealed for $int {}
+
         impl FormatArgument for $int {
             type Details = ();
+            const DEFAULT_DETAILS: () = ();
             const MAX_BYTES_PER_CHAR: usize = 1;
         }
         )+
@@ -313,13 +1689,34 @@ macro_rules! impl_max_width_for_int {
 
 impl_max_width_for_int!(i8, i16, i32, i64, i128, isize);
 
+impl<T: MaxLength> MaxLength for &T {
+    const MAX_LENGTH: StrLength = T::MAX_LENGTH;
+}
+
 impl MaxLength for char {
-    const MAX_LENGTH: StrLength = StrLength { bytes: 4, chars: 1 };
+    const MAX_LENGTH: StrLength = StrLength {
+        bytes: 4,
+        chars: 1,
+        columns: 2,
+    };
 }
 
+impl private::Sealed for char {}
+
 impl FormatArgument for char {
-    type Details = ();
+    type Details = StrFormat;
+    const DEFAULT_DETAILS: StrFormat = StrFormat::UNCLIPPED;
     const MAX_BYTES_PER_CHAR: usize = 4;
+    const DEBUG_QUOTE: char = '\'';
+}
+
+impl private::Sealed for &char {}
+
+impl FormatArgument for &char {
+    type Details = StrFormat;
+    const DEFAULT_DETAILS: StrFormat = StrFormat::UNCLIPPED;
+    const MAX_BYTES_PER_CHAR: usize = 4;
+    const DEBUG_QUOTE: char = '\'';
 }
 
 #[cfg(test)]
@@ -327,6 +1724,7 @@ mod tests {
     use std::string::ToString;
 
     use super::*;
+    use crate::{AsciiArgs, CompileArgs};
 
     #[test]
     fn max_length_bound_is_correct() {
@@ -374,4 +1772,167 @@ mod tests {
         assert_eq!(format.capacity.bytes, 16 + "…".len());
         assert_eq!(format.capacity(), 23); // 20 (5 chars * 4 bytes) + 3 padding chars * 4 bytes each
     }
+
+    #[test]
+    fn capacity_for_format_padded_with_pattern() {
+        let format = fmt::<u8>().pad_left_pattern(8, "=-");
+        assert_eq!(format.capacity(), 8);
+        let format = fmt::<u8>().pad_left_pattern(8, "-💣");
+        assert_eq!(format.capacity(), 32);
+        let format = clip_ascii(4, "").pad_left_pattern(8, "ℝ.");
+        assert_eq!(format.capacity(), 24);
+    }
+
+    #[test]
+    fn capacity_for_format_with_affixes() {
+        let format = fmt::<u8>().prefix("0x").suffix("h");
+        assert_eq!(format.capacity(), 6);
+        let format = fmt::<u8>().prefix("0x").pad_left(8, ' ');
+        assert_eq!(format.capacity(), 8);
+        let format = fmt::<u8>().prefix("→").pad_left(8, ' ');
+        assert_eq!(format.capacity(), 4 * 3 + 4);
+        // ^ 4 chars of the prefixed value can take up to 3 bytes each; 4 chars of padding
+        let format = clip(4, "…").suffix("!");
+        assert_eq!(format.capacity(), 4 * 4 + "…".len() + 1);
+    }
+
+    #[test]
+    fn capacity_for_format_padded_in_columns() {
+        let format = clip(4, "").pad_left_columns(8, ' ');
+        assert_eq!(format.capacity(), 16 + 8);
+        let format = fmt::<u8>().pad_left_columns(2, ' ');
+        assert_eq!(format.capacity(), 3 + 2);
+    }
+
+    #[test]
+    fn capacity_for_clipped_strings() {
+        assert_eq!(clip(4, "…").capacity(), clip_start(4, "…").capacity());
+        assert_eq!(elide_middle(2, 2, "…").capacity(), clip(4, "…").capacity());
+        assert_eq!(elide_middle(2, 2, "…").capacity.chars, 5);
+        assert_eq!(elide_middle_ascii(3, 2, "..").capacity(), 7);
+        assert_eq!(clip_start_ascii(3, "..").capacity(), 5);
+    }
+
+    #[test]
+    fn capacity_for_strings_clipped_by_graphemes() {
+        let format = clip_graphemes(2, "…");
+        assert_eq!(format.capacity(), 64 + "…".len());
+        assert_eq!(format.capacity.chars, 17);
+        let long_cluster = "a\u{301}\u{302}\u{303}\u{304}\u{305}\u{306}\u{307}💣";
+        let arg = crate::ArgumentWrapper::new(long_cluster)
+            .with_fmt(clip_graphemes(1, ""))
+            .into_argument();
+        assert!(arg.formatted_len() <= clip_graphemes(1, "").capacity());
+    }
+
+    #[test]
+    fn capacity_for_clipped_strings_with_omitted_count() {
+        let format = clip_ascii(4, "..").with_omitted_count();
+        assert_eq!(
+            format.capacity(),
+            6 + " (+18446744073709551615 chars)".len()
+        );
+        let format = clip_bytes(4, "").with_omitted_count();
+        assert_eq!(
+            format.capacity(),
+            4 + " (+18446744073709551615 bytes)".len()
+        );
+
+        // The omitted count is bounded by the capacity of nested args.
+        let format = fmt::<&CompileArgs<16>>().clip(4, "").with_omitted_count();
+        assert_eq!(format.capacity(), 4 * 4 + " (+16 chars)".len());
+        let format = fmt::<&AsciiArgs<128>>().clip(4, "").with_omitted_count();
+        assert_eq!(format.capacity(), 4 + " (+128 chars)".len());
+        let format = fmt::<&CompileArgs<9>>().clip(4, "").with_omitted_count();
+        assert_eq!(format.capacity(), 9 + " (+9 chars)".len());
+    }
+
+    #[test]
+    #[should_panic(expected = "Omitted count can only be specified for clipping formats")]
+    fn omitted_count_for_unclipped_format() {
+        let _ = fmt::<&CompileArgs<16>>().with_omitted_count();
+    }
+
+    #[test]
+    fn capacity_for_clipped_nested_args() {
+        let format = fmt::<&CompileArgs<16>>();
+        assert_eq!(format.capacity(), 16);
+        let format = fmt::<&CompileArgs<16>>().clip(8, "");
+        assert_eq!(format.capacity(), 16);
+        let format = fmt::<&CompileArgs<16>>().clip(8, "…");
+        assert_eq!(format.capacity(), 16 + "…".len());
+        let format = fmt::<&CompileArgs<16>>().clip(2, "…");
+        assert_eq!(format.capacity.chars, 3);
+        assert_eq!(format.capacity(), 8 + "…".len());
+
+        let format = fmt::<&CompileArgs<16>>().clip(2, "…").pad_left(4, ' ');
+        assert_eq!(format.capacity(), 13); // 3 chars * 4 bytes + 1 padding char
+    }
+
+    #[test]
+    fn capacity_for_transformed_strings() {
+        let format = str_fmt().to_ascii_uppercase().clip(4, "…");
+        assert_eq!(format.capacity(), clip(4, "…").capacity());
+        let format = clip_ascii(4, "..").to_ascii_lowercase();
+        assert_eq!(format.capacity(), 6);
+        let format = fmt::<&CompileArgs<16>>().to_ascii_uppercase().clip(8, "");
+        assert_eq!(format.capacity(), 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "String format is unbounded")]
+    fn capacity_for_unclipped_string_format() {
+        str_fmt().to_ascii_uppercase().capacity();
+    }
+
+    #[test]
+    #[should_panic(expected = "String format can contain at most one clipping transform")]
+    fn multiple_clipping_transforms() {
+        let _ = clip(4, "").to_ascii_uppercase().clip(2, "");
+    }
+
+    #[test]
+    fn capacity_for_debug_formats() {
+        let format = fmt::<char>().debug();
+        assert_eq!(format.capacity(), "'\\u{10ffff}'".len());
+        let format = clip_ascii(4, "").debug();
+        assert_eq!(format.capacity(), 4 * "\\u{1f}".len() + 2);
+        let format = clip(4, "…").debug();
+        assert_eq!(format.capacity(), 5 * "\\u{10ffff}".len() + 2);
+        let format = str_fmt().debug().clip(4, "…");
+        assert_eq!(format.capacity(), 4 * 4 + "…".len() + 2);
+        let format = str_fmt().debug().clip(4, "\"");
+        assert_eq!(format.capacity(), 4 * 4 + "\\\"".len() + 2);
+        let format = str_fmt().debug().clip(4, "…").with_omitted_count();
+        let omitted_len = " (+".len() + usize::MAX_LENGTH.bytes + " chars)".len();
+        assert_eq!(format.capacity(), 4 * 4 + "…".len() + 2 + omitted_len);
+    }
+
+    #[test]
+    fn capacity_for_percent_encoding() {
+        let set = PercentEncodeSet::QUERY;
+        let format = clip(4, "").percent_encode(set);
+        assert_eq!(format.capacity(), 4 * "%F0%9F%92%A3".len());
+        let format = clip_ascii(4, "").percent_encode(set);
+        assert_eq!(format.capacity(), 4 * "%20".len());
+        let format = clip_bytes(5, "").percent_encode(set);
+        assert_eq!(format.capacity(), 5 * "%20".len());
+        let format = percent_encode(set).clip(4, "…");
+        assert_eq!(format.capacity(), 4 * 4 + "%E2%80%A6".len());
+        let format = percent_encode(set).clip(4, "").with_omitted_count();
+        let omitted_len = "%20(%2B".len() + usize::MAX_LENGTH.bytes + "%20chars)".len();
+        assert_eq!(format.capacity(), 4 * 4 + omitted_len);
+    }
+
+    #[test]
+    #[should_panic(expected = "String format is already quoted")]
+    fn multiple_debug_transforms() {
+        let _ = fmt::<char>().debug().debug();
+    }
+
+    #[test]
+    #[should_panic(expected = "Char transforms cannot follow quoting transforms")]
+    fn char_map_after_quoting() {
+        let _ = str_fmt().json_str().to_ascii_uppercase();
+    }
 }
@@ -0,0 +1,79 @@
+//! JSON-specific conversions used by the `compile_json!` macro.
+
+use crate::{
+    argument::{ArgumentWrapper, Ascii},
+    format::{unbounded, Fmt, Quoting},
+    AsciiArgs, CompileArgs,
+};
+
+/// Implements JSON value conversions for a string-like type (i.e., one with `StrFormat` details).
+/// Strings are escaped and quoted unless the format is already quoted with [`Fmt::json_str()`];
+/// other quoting (e.g., with [`Fmt::debug()`]) would produce invalid JSON and is rejected.
+macro_rules! impl_json_str {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> Fmt<$ty> {
+            #[doc(hidden)] // implementation detail of the `compile_json` macro
+            #[must_use]
+            pub const fn json_value(self) -> Self {
+                match self.details.quoting {
+                    None => self.json_str(),
+                    Some(Quoting::Json) => self,
+                    Some(_) => panic!("JSON string values can only be quoted with `json_str()`"),
+                }
+            }
+        }
+
+        impl<$($generics)*> ArgumentWrapper<$ty> {
+            #[doc(hidden)] // implementation detail of the `compile_json` macro
+            #[must_use]
+            pub const fn json_value(self) -> Self {
+                let fmt = match self.fmt {
+                    Some(fmt) => fmt,
+                    None => unbounded(),
+                };
+                self.with_fmt(fmt.json_value())
+            }
+        }
+    };
+}
+
+impl_json_str!(['a] &'a str);
+impl_json_str!(['a] Ascii<'a>);
+impl_json_str!(['a, const CAP: usize] &'a CompileArgs<CAP>);
+impl_json_str!(['a, const CAP: usize] &'a AsciiArgs<CAP>);
+impl_json_str!([] char);
+impl_json_str!(['a] &'a char);
+
+/// Implements JSON value conversions for an integer type. Integers are output as is.
+macro_rules! impl_json_int {
+    ($($int:ty),+) => {
+        $(
+        impl Fmt<$int> {
+            #[doc(hidden)] // implementation detail of the `compile_json` macro
+            #[must_use]
+            pub const fn json_value(self) -> Self {
+                self
+            }
+        }
+
+        impl ArgumentWrapper<$int> {
+            #[doc(hidden)] // implementation detail of the `compile_json` macro
+            #[must_use]
+            pub const fn json_value(self) -> Self {
+                self
+            }
+        }
+
+        impl ArgumentWrapper<&$int> {
+            #[doc(hidden)] // implementation detail of the `compile_json` macro
+            #[must_use]
+            pub const fn json_value(self) -> Self {
+                self
+            }
+        }
+        )+
+    };
+}
+
+impl_json_int!(u8, u16, u32, u64, u128, usize);
+impl_json_int!(i8, i16, i32, i64, i128, isize);
@@ -22,10 +22,15 @@
 //! # Limitations
 //!
 //! - Only a few types from the standard library can be formatted: integers, `char`s and `str`ings.
-//! - Formatting specifiers do not support hex encoding, debug formatting etc.
-//! - Padding logic assumes that any Unicode char has identical displayed width, which isn't really
-//!   true (e.g., there are chars that have zero width and instead combine with the previous char).
-//!   The same assumption is made by the `std` padding logic.
+//! - Formatting specifiers do not support hex encoding etc. Debug formatting is only supported
+//!   for strings and `char`s (`Fmt::debug()`); printable chars are determined using tables generated
+//!   from the Unicode Character Database (Unicode 17.0), so escaping may differ from the standard library
+//!   only if the latter uses another Unicode version.
+//! - Padding logic (`pad_left()` etc.) assumes that any Unicode char has identical displayed width,
+//!   which isn't really true (e.g., there are chars that have zero width and instead combine
+//!   with the previous char). The same assumption is made by the `std` padding logic.
+//!   `pad_left_columns()` etc. measure the displayed width based on the `East_Asian_Width`
+//!   and grapheme cluster properties, which may be imprecise for rare chars and terminals.
 //!
 //! # Alternatives and similar tools
 //!
@@ -129,30 +134,58 @@
     clippy::module_name_repetitions
 )]
 
-use core::{fmt, slice, str};
+use core::{borrow::Borrow, cmp::Ordering, fmt, hash, marker::PhantomData, ops, slice, str};
 #[cfg(test)]
 extern crate std;
 
 mod argument;
 mod format;
+mod json;
+mod logfmt;
 mod macros;
+mod percent;
 #[cfg(test)]
 mod tests;
+mod transform;
+mod unicode;
 mod utils;
 
 #[doc(hidden)]
 pub use crate::argument::{Argument, ArgumentWrapper};
 pub use crate::{
-    argument::Ascii,
-    format::{clip, clip_ascii, fmt, Fmt, FormatArgument, MaxLength, StrLength},
+    argument::{Ascii, AsciiArgs},
+    format::{
+        clip, clip_ascii, clip_bytes, clip_graphemes, clip_start, clip_start_ascii, clip_words,
+        elide_middle, elide_middle_ascii, fmt, percent_encode, str_fmt, CenterBias, Fmt,
+        FormatArgument, MaxLength, StrLength,
+    },
+    percent::PercentEncodeSet,
 };
-use crate::{format::StrFormat, utils::ClippedStr};
+use crate::{format::StrFormat, transform::StrSink, utils::bytes_match_at};
 
 /// Formatted string returned by the [`compile_args!`] macro, similar to [`Arguments`](fmt::Arguments).
 ///
 /// The type parameter specifies the compile-time upper boundary of the formatted string length in bytes.
 /// It is not necessarily equal to the actual byte length of the formatted string.
-#[derive(Debug)]
+///
+/// `CompileArgs` dereference to `str` and implement comparison / hashing traits similar to `str`.
+/// All these traits take into account only the formatted string, not the unused buffer capacity.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::{compile_args, CompileArgs};
+/// # use std::collections::HashSet;
+/// const ARGS: CompileArgs<9> = compile_args!(2_u32, " + ", 2_u32, " = ", 2_u32 + 2);
+/// assert_eq!(ARGS, "2 + 2 = 4");
+/// assert_eq!(ARGS.len(), 9);
+/// assert!(ARGS.starts_with(char::is_numeric)); // uses `Deref<Target = str>`
+/// assert!(ARGS.contains('='));
+///
+/// let set: HashSet<_> = [ARGS, ARGS].into_iter().collect();
+/// assert_eq!(set.len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy)]
 pub struct CompileArgs<const CAP: usize> {
     buffer: [u8; CAP],
     len: usize,
@@ -164,12 +197,111 @@ impl<const CAP: usize> fmt::Display for CompileArgs<CAP> {
     }
 }
 
+/// Allows using `CompileArgs` as a bounded stack-allocated string in runtime, e.g. with the [`write!`] macro.
+///
+/// If a written string does not fit into the remaining capacity, it is truncated at a char boundary
+/// and an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::CompileArgs;
+/// use core::fmt::Write as _;
+///
+/// let mut args = CompileArgs::<8>::default();
+/// write!(args, "{}+{}", 2, 2).unwrap();
+/// assert_eq!(args.as_str(), "2+2");
+/// assert!(write!(args, "={}💣", 4).is_err());
+/// // The string is truncated at the char boundary
+/// assert_eq!(args.as_str(), "2+2=4");
+/// ```
+impl<const CAP: usize> fmt::Write for CompileArgs<CAP> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let remaining_capacity = CAP - self.len;
+        let (written, result) = if s.len() <= remaining_capacity {
+            (s, Ok(()))
+        } else {
+            let mut boundary = remaining_capacity;
+            while !s.is_char_boundary(boundary) {
+                boundary -= 1;
+            }
+            (&s[..boundary], Err(fmt::Error))
+        };
+
+        let new_len = self.len + written.len();
+        self.buffer[self.len..new_len].copy_from_slice(written.as_bytes());
+        self.len = new_len;
+        result
+    }
+}
+
 impl<const CAP: usize> AsRef<str> for CompileArgs<CAP> {
     fn as_ref(&self) -> &str {
         self.as_str()
     }
 }
 
+impl<const CAP: usize> Borrow<str> for CompileArgs<CAP> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> ops::Deref for CompileArgs<CAP> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> Default for CompileArgs<CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAP: usize, const OTHER_CAP: usize> PartialEq<CompileArgs<OTHER_CAP>>
+    for CompileArgs<CAP>
+{
+    fn eq(&self, other: &CompileArgs<OTHER_CAP>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const CAP: usize> PartialEq<str> for CompileArgs<CAP> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const CAP: usize> PartialEq<&str> for CompileArgs<CAP> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const CAP: usize> Eq for CompileArgs<CAP> {}
+
+impl<const CAP: usize> PartialOrd for CompileArgs<CAP> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const CAP: usize> Ord for CompileArgs<CAP> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const CAP: usize> hash::Hash for CompileArgs<CAP> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        // Must be consistent with the `Borrow<str>` implementation.
+        self.as_str().hash(state);
+    }
+}
+
 impl<const CAP: usize> CompileArgs<CAP> {
     /// Capacity of these arguments in bytes.
     pub const CAPACITY: usize = CAP;
@@ -177,10 +309,17 @@ impl<const CAP: usize> CompileArgs<CAP> {
     #[doc(hidden)] // Implementation detail of the `compile_args` macro
     #[track_caller]
     pub const fn assert_capacity(required_capacity: usize) {
+        Self::assert_capacity_for("compile_args", required_capacity);
+    }
+
+    #[doc(hidden)] // Implementation detail of crate macros
+    #[track_caller]
+    pub const fn assert_capacity_for(macro_name: &str, required_capacity: usize) {
         compile_assert!(
             CAP >= required_capacity,
             "Insufficient capacity (", CAP => fmt::<usize>(), " bytes) provided \
-             for `compile_args` macro; it requires at least ", required_capacity => fmt::<usize>(), " bytes"
+             for `", macro_name => clip(16, ""), "` macro; it requires at least ",
+            required_capacity => fmt::<usize>(), " bytes"
         );
     }
 
@@ -193,20 +332,12 @@ impl<const CAP: usize> CompileArgs<CAP> {
 
     const fn write_str(self, s: &str, fmt: Option<StrFormat>) -> Self {
         match fmt {
-            Some(StrFormat { clip_at, using }) => {
-                let clipped = ClippedStr::new(s, clip_at);
-                match clipped {
-                    ClippedStr::Full(bytes) => self.write_str_bytes(bytes),
-                    ClippedStr::Clipped(bytes) => self
-                        .write_str_bytes(bytes)
-                        .write_str_bytes(using.as_bytes()),
-                }
-            }
-            _ => self.write_str_bytes(s.as_bytes()),
+            Some(fmt) => fmt.apply(s, StrSink::new(self)).into_args(),
+            None => self.write_str_bytes(s.as_bytes()),
         }
     }
 
-    const fn write_str_bytes(self, s_bytes: &[u8]) -> Self {
+    pub(crate) const fn write_str_bytes(self, s_bytes: &[u8]) -> Self {
         let new_len = self.len + s_bytes.len();
         let mut buffer = self.buffer;
         let mut pos = self.len;
@@ -223,7 +354,7 @@ impl<const CAP: usize> CompileArgs<CAP> {
 
     /// Writes a char to this string. Largely copied from the standard library with minor changes.
     #[allow(clippy::cast_possible_truncation)] // false positive
-    const fn write_char(self, c: char) -> Self {
+    pub(crate) const fn write_char(self, c: char) -> Self {
         const TAG_CONT: u8 = 0b_1000_0000;
         const TAG_TWO_BYTES: u8 = 0b_1100_0000;
         const TAG_THREE_BYTES: u8 = 0b_1110_0000;
@@ -273,19 +404,234 @@ impl<const CAP: usize> CompileArgs<CAP> {
         this
     }
 
+    /// Formats the provided arguments in runtime, e.g., ones produced by the [`format_args!`] macro.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the formatted string does not fit into the capacity, or if one
+    /// of the formatted values returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::CompileArgs;
+    /// let args = CompileArgs::<16>::from_fmt(format_args!("{:?}", [1, 2])).unwrap();
+    /// assert_eq!(args.as_str(), "[1, 2]");
+    /// assert!(CompileArgs::<4>::from_fmt(format_args!("{:?}", [1, 2])).is_err());
+    /// ```
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Result<Self, fmt::Error> {
+        let mut this = Self::new();
+        fmt::Write::write_fmt(&mut this, args)?;
+        Ok(this)
+    }
+
+    /// Concatenates the provided sequence of [`Argument`]s, checking capacity in compile time.
+    #[doc(hidden)] // implementation detail of the `concat_args` macro
+    pub const fn concat<const LITERALS_CAP: usize, T: ConcatCapacity>(
+        arguments: &[Argument],
+        _dynamic_args: &T,
+    ) -> Self {
+        let () = ConcatCheck::<T, LITERALS_CAP, CAP>::CAPACITY;
+        Self::format(arguments)
+    }
+
     /// Returns the `str` value of this formatter.
     pub const fn as_str(&self) -> &str {
-        unsafe {
-            // SAFETY: This is equivalent to `&self.buffer[..self.len]`, only works in compile time.
-            let written_slice = slice::from_raw_parts(self.buffer.as_ptr(), self.len);
-            // SAFETY: Safe by construction; written bytes form a valid `str`.
-            str::from_utf8_unchecked(written_slice)
+        // SAFETY: Safe by construction; written bytes form a valid `str`.
+        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Returns the bytes of the formatted string.
+    pub const fn as_bytes(&self) -> &[u8] {
+        // SAFETY: This is equivalent to `&self.buffer[..self.len]`, only works in compile time.
+        unsafe { slice::from_raw_parts(self.buffer.as_ptr(), self.len) }
+    }
+
+    /// Returns the length of the formatted string in bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the formatted string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Checks whether the formatted string is equal to the provided string. Unlike `PartialEq`,
+    /// this method can be used in compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::compile_args;
+    /// // Verify the message format at build time.
+    /// const _: () = assert!(compile_args!("The answer is ", 42_u32).eq_str("The answer is 42"));
+    /// ```
+    pub const fn eq_str(&self, s: &str) -> bool {
+        self.len == s.len() && bytes_match_at(self.as_bytes(), s.as_bytes(), 0)
+    }
+
+    /// Checks whether the formatted string starts with the provided prefix. Unlike `str::starts_with()`
+    /// available via `Deref`, this method can be used in compile time.
+    pub const fn starts_with_str(&self, prefix: &str) -> bool {
+        bytes_match_at(self.as_bytes(), prefix.as_bytes(), 0)
+    }
+
+    /// Checks whether the formatted string ends with the provided suffix. Unlike `str::ends_with()`
+    /// available via `Deref`, this method can be used in compile time.
+    pub const fn ends_with_str(&self, suffix: &str) -> bool {
+        if suffix.len() > self.len {
+            return false;
+        }
+        bytes_match_at(self.as_bytes(), suffix.as_bytes(), self.len - suffix.len())
+    }
+
+    /// Checks whether the formatted string contains the provided substring. Unlike `str::contains()`
+    /// available via `Deref`, this method can be used in compile time.
+    pub const fn contains_str(&self, needle: &str) -> bool {
+        let mut start = 0;
+        while start + needle.len() <= self.len {
+            if bytes_match_at(self.as_bytes(), needle.as_bytes(), start) {
+                return true;
+            }
+            start += 1;
+        }
+        false
+    }
+
+    /// Converts these arguments to ones with a greater or equal capacity. This is useful
+    /// to unify types of arguments, e.g. in different `match` arms.
+    ///
+    /// Capacity is checked in compile time; the conversion will fail with a compilation error
+    /// if `NEW_CAP` is less than the capacity of these arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, fmt, CompileArgs};
+    /// const fn describe(value: Option<u32>) -> CompileArgs<20> {
+    ///     match value {
+    ///         Some(value) => compile_args!("value: ", value => fmt::<u32>()).widen(),
+    ///         None => compile_args!("no value").widen(),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(describe(Some(42)).as_str(), "value: 42");
+    /// assert_eq!(describe(None).as_str(), "no value");
+    /// ```
+    ///
+    /// Narrowing capacity leads to a compilation error:
+    ///
+    /// ```compile_fail
+    /// # use compile_fmt::{compile_args, CompileArgs};
+    /// let args: CompileArgs<4> = compile_args!("Value: ", 42_i32).widen();
+    /// ```
+    pub const fn widen<const NEW_CAP: usize>(self) -> CompileArgs<NEW_CAP> {
+        let () = CapacityCheck::<CAP, NEW_CAP>::WIDEN;
+        self.resize_unchecked()
+    }
+
+    /// Tries to convert these arguments to ones with the specified capacity. Unlike [`Self::widen()`],
+    /// the capacity is checked against the actual byte length of the formatted string, rather than
+    /// against `CAP`.
+    ///
+    /// Returns `None` if the formatted string does not fit into `NEW_CAP` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use compile_fmt::{compile_args, clip, CompileArgs};
+    /// let s = "test";
+    /// let args = compile_args!("[", s => clip(16, "…"), "]");
+    /// assert!(args.len() < args.capacity());
+    /// let args: CompileArgs<8> = args.try_shrink().unwrap();
+    /// assert_eq!(args.as_str(), "[test]");
+    /// assert!(args.try_shrink::<4>().is_none());
+    /// ```
+    pub const fn try_shrink<const NEW_CAP: usize>(self) -> Option<CompileArgs<NEW_CAP>> {
+        if self.len > NEW_CAP {
+            None
+        } else {
+            Some(self.resize_unchecked())
+        }
+    }
+
+    /// Converts these arguments to ones with the specified capacity, which may be lesser or greater
+    /// than the original one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the formatted string does not fit into `NEW_CAP` bytes. If called in compile time,
+    /// the panic will lead to a compilation error.
+    #[track_caller]
+    pub const fn resize<const NEW_CAP: usize>(self) -> CompileArgs<NEW_CAP> {
+        compile_assert!(
+            self.len <= NEW_CAP,
+            "Cannot resize arguments with length ", self.len => fmt::<usize>(),
+            " bytes to capacity ", NEW_CAP => fmt::<usize>(), " bytes"
+        );
+        self.resize_unchecked()
+    }
+
+    /// Returns the capacity of these arguments in bytes. Equivalent to [`Self::CAPACITY`].
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    const fn resize_unchecked<const NEW_CAP: usize>(self) -> CompileArgs<NEW_CAP> {
+        let mut buffer = [0_u8; NEW_CAP];
+        let mut pos = 0;
+        while pos < self.len {
+            buffer[pos] = self.buffer[pos];
+            pos += 1;
+        }
+        CompileArgs {
+            buffer,
+            len: self.len,
         }
     }
 }
 
+/// Total capacity of dynamic arguments in the [`concat_args!`] macro. Implemented for nested tuples
+/// like `(&CompileArgs<A>, (&CompileArgs<B>, ()))`.
+#[doc(hidden)] // implementation detail of `concat_args`
+pub trait ConcatCapacity {
+    const CAPACITY: usize;
+}
+
+impl ConcatCapacity for () {
+    const CAPACITY: usize = 0;
+}
+
+impl<H: MaxLength, T: ConcatCapacity> ConcatCapacity for (H, T) {
+    const CAPACITY: usize = H::MAX_LENGTH.bytes + T::CAPACITY;
+}
+
+struct ConcatCheck<T, const LITERALS_CAP: usize, const CAP: usize>(PhantomData<T>);
+
+impl<T: ConcatCapacity, const LITERALS_CAP: usize, const CAP: usize>
+    ConcatCheck<T, LITERALS_CAP, CAP>
+{
+    const CAPACITY: () =
+        CompileArgs::<CAP>::assert_capacity_for("concat_args", LITERALS_CAP + T::CAPACITY);
+}
+
+/// Compile-time capacity checks for [`CompileArgs`] conversions.
+struct CapacityCheck<const CAP: usize, const NEW_CAP: usize>;
+
+impl<const CAP: usize, const NEW_CAP: usize> CapacityCheck<CAP, NEW_CAP> {
+    const WIDEN: () = compile_assert!(
+        NEW_CAP >= CAP,
+        "Cannot widen `CompileArgs` with capacity ", CAP => fmt::<usize>(),
+        " bytes to lesser capacity ", NEW_CAP => fmt::<usize>(), " bytes"
+    );
+}
+
+impl<const CAP: usize> format::private::Sealed for &CompileArgs<CAP> {}
+
 impl<const CAP: usize> FormatArgument for &CompileArgs<CAP> {
-    type Details = ();
+    type Details = StrFormat;
+    const DEFAULT_DETAILS: StrFormat = StrFormat::UNCLIPPED;
     const MAX_BYTES_PER_CHAR: usize = 4;
 }
 
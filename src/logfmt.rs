@@ -0,0 +1,135 @@
+//! logfmt-specific conversions used by the `compile_kv!` macro.
+
+use crate::{
+    argument::{ArgumentWrapper, Ascii},
+    format::{unbounded, Fmt, Quoting},
+    transform::CharMap,
+    utils::decode_char,
+    AsciiArgs, CompileArgs,
+};
+
+/// Checks whether a string containing the specified bytes must be quoted as a logfmt value;
+/// i.e., whether it contains whitespace (including non-ASCII whitespace, such as U+00A0 no-break space),
+/// `=`, `"`, `\` or ASCII control chars. `bytes` must be a valid UTF-8 sequence.
+pub(crate) const fn needs_quotes(bytes: &[u8]) -> bool {
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let (c, char_len) = decode_char(bytes, pos);
+        let needs_quotes = matches!(
+            c,
+            // ASCII whitespace and control chars, `"`, `=` and `\`
+            0..=0x20 | 0x7f | 0x22 | 0x3d | 0x5c
+            // Non-ASCII chars with the `White_Space` property
+            | 0x85 | 0xa0 | 0x1680 | 0x2000..=0x200a | 0x2028 | 0x2029 | 0x202f | 0x205f | 0x3000
+        );
+        if needs_quotes {
+            return true;
+        }
+        pos += char_len;
+    }
+    false
+}
+
+/// Implements logfmt value conversions for a string-like type (i.e., one with `StrFormat` details).
+/// Strings are quoted and escaped only if necessary, unless the format is already quoted.
+macro_rules! impl_kv_str {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> Fmt<$ty> {
+            #[doc(hidden)] // implementation detail of the `compile_kv` macro
+            #[must_use]
+            pub const fn kv_value(self) -> Self {
+                if self.details.quoting.is_some() {
+                    self
+                } else {
+                    self.with_quoting_map(CharMap::EscapeJson, Quoting::JsonIfNeeded)
+                }
+            }
+        }
+
+        impl<$($generics)*> ArgumentWrapper<$ty> {
+            #[doc(hidden)] // implementation detail of the `compile_kv` macro
+            #[must_use]
+            pub const fn kv_value(self) -> Self {
+                let fmt = match self.fmt {
+                    Some(fmt) => fmt,
+                    None => unbounded(),
+                };
+                self.with_fmt(fmt.kv_value())
+            }
+        }
+    };
+}
+
+impl_kv_str!(['a] &'a str);
+impl_kv_str!(['a] Ascii<'a>);
+impl_kv_str!(['a, const CAP: usize] &'a CompileArgs<CAP>);
+impl_kv_str!(['a, const CAP: usize] &'a AsciiArgs<CAP>);
+impl_kv_str!([] char);
+impl_kv_str!(['a] &'a char);
+
+/// Implements logfmt value conversions for an integer type. Integers are output as is.
+macro_rules! impl_kv_int {
+    ($($int:ty),+) => {
+        $(
+        impl Fmt<$int> {
+            #[doc(hidden)] // implementation detail of the `compile_kv` macro
+            #[must_use]
+            pub const fn kv_value(self) -> Self {
+                self
+            }
+        }
+
+        impl ArgumentWrapper<$int> {
+            #[doc(hidden)] // implementation detail of the `compile_kv` macro
+            #[must_use]
+            pub const fn kv_value(self) -> Self {
+                self
+            }
+        }
+
+        impl ArgumentWrapper<&$int> {
+            #[doc(hidden)] // implementation detail of the `compile_kv` macro
+            #[must_use]
+            pub const fn kv_value(self) -> Self {
+                self
+            }
+        }
+        )+
+    };
+}
+
+impl_kv_int!(u8, u16, u32, u64, u128, usize);
+impl_kv_int!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checking_whether_quotes_are_needed() {
+        for s in ["error", "a/b.rs", "тест", "42", "-", "a'b"] {
+            assert!(!needs_quotes(s.as_bytes()), "{s:?}");
+        }
+        for s in ["a b", "a=b", "\"", "a\\b", "\n", "\u{7f}", "\u{0}"] {
+            assert!(needs_quotes(s.as_bytes()), "{s:?}");
+        }
+        for s in [
+            "a\u{85}b",
+            "a\u{a0}b",
+            "\u{1680}",
+            "\u{2000}",
+            "a\u{200a}",
+            "\u{2028}",
+            "\u{2029}",
+            "\u{202f}",
+            "\u{205f}",
+            "\u{3000}",
+        ] {
+            assert!(needs_quotes(s.as_bytes()), "{s:?}");
+        }
+        // Zero-width and other non-whitespace format chars do not require quoting.
+        for s in ["a\u{200b}b", "\u{2027}", "\u{2030}", "\u{3001}"] {
+            assert!(!needs_quotes(s.as_bytes()), "{s:?}");
+        }
+    }
+}
@@ -12,14 +12,42 @@
 /// - Strings (`&str`)
 /// - [`Ascii`](crate::Ascii) strings
 /// - Chars (`char`)
-/// - References to [`CompileArgs`](crate::CompileArgs).
+/// - References to [`CompileArgs`](crate::CompileArgs) and [`AsciiArgs`](crate::AsciiArgs).
 ///
-/// Due to how Rust type inference works, you might need to specify the type suffix for integer
-/// literals (e.g., `42_usize` instead of `42`).
+/// Unsuffixed integer literals (e.g., `42`) are treated as `i32`, same as in the rest of Rust.
+/// Consequently, a literal out of the `i32` range (e.g., `3_000_000_000`) leads to a compilation error
+/// and requires a type suffix (e.g., `3_000_000_000_u32`).
 ///
 /// Optionally, an argument may specify its [format](crate::Fmt) as `$arg => $fmt`.
+/// A format with dynamic padding (e.g., [`Fmt::pad_left_dyn()`](crate::Fmt::pad_left_dyn())) may be followed
+/// by the padding width as `$arg => $fmt; $width`; unlike the format, the width may be dynamic.
+/// Specifying the width for other formats is a compilation error.
 /// A format is mandatory if the argument is not a constant; e.g. if it is an argument or a local variable
-/// in a `const fn`.
+/// in a `const fn`. This is because the capacity of the produced `CompileArgs` must be computed
+/// in a constant context, which cannot refer to dynamic arguments (and on stable Rust, cannot refer
+/// to their types either). If the capacity is known from the context, [`concat_args!`](crate::concat_args) can be used
+/// instead; it does not require formats for dynamic integers and `char`s.
+///
+/// ```compile_fail
+/// # use compile_fmt::compile_args;
+/// const fn format_value(x: u32) -> impl AsRef<str> {
+///     compile_args!("x=", x) // error: attempt to use a non-constant value in a constant
+/// }
+/// ```
+///
+/// ```
+/// # use compile_fmt::{concat_args, CompileArgs};
+/// const fn format_value(x: u32) -> CompileArgs<12> {
+///     concat_args!("x=", x) // capacity is checked against `u32::MAX`
+/// }
+/// assert_eq!(format_value(42).as_str(), "x=42");
+/// ```
+///
+/// ```compile_fail
+/// # use compile_fmt::{compile_args, fmt};
+/// let s = compile_args!("[", 42 => fmt::<i32>().pad_left(8, ' '); 3, "]");
+/// // error: padding width can only be specified for formats with dynamic padding
+/// ```
 ///
 /// The value output by the macro is [`CompileArgs`](crate::CompileArgs).
 ///
@@ -44,6 +72,9 @@
 /// # use compile_fmt::{compile_args, CompileArgs};
 /// const ARGS: CompileArgs<9> = compile_args!(2_u32, " + ", 2_u32, " = ", 2_u32 + 2);
 /// assert_eq!(ARGS.as_str(), "2 + 2 = 4");
+/// // Unsuffixed integer literals are supported as well
+/// const OTHER_ARGS: CompileArgs<9> = compile_args!(2, " * ", 2, " = ", 2 * 2);
+/// assert_eq!(OTHER_ARGS.as_str(), "2 * 2 = 4");
 /// ```
 ///
 /// ## Usage in `const fn` with dynamic args
@@ -94,21 +125,31 @@
 /// ```
 #[macro_export]
 macro_rules! compile_args {
-    (capacity: $cap:expr, $($arg:expr $(=> $fmt:expr)?),+) => {{
+    (capacity: $cap:expr, $($arg:expr $(=> $fmt:expr $(; $width:expr)?)?),+) => {{
         const __CAPACITY: usize = $cap;
         const _: () = {
             let required_capacity = $crate::__compile_args_impl!(@total_capacity $($arg $(=> $fmt)?,)+);
             $crate::CompileArgs::<__CAPACITY>::assert_capacity(required_capacity);
+            $($($crate::__compile_args_impl!(@assert_dynamic_pad $fmt $(; $width)?);)?)+
         };
         $crate::CompileArgs::<__CAPACITY>::format(&[
-            $($crate::ArgumentWrapper::new($arg)$(.with_fmt($fmt))?.into_argument(),)+
+            $(
+                $crate::ArgumentWrapper::new($arg)$(.with_fmt($fmt))?.into_argument()
+                    $($(.with_pad_width($width))?)?,
+            )+
         ]) as $crate::CompileArgs<__CAPACITY>
         // ^ The type hint sometimes helps in const contexts
     }};
-    ($($arg:expr $(=> $fmt:expr)?),+) => {{
+    ($($arg:expr $(=> $fmt:expr $(; $width:expr)?)?),+) => {{
         const __CAPACITY: usize = $crate::__compile_args_impl!(@total_capacity $($arg $(=> $fmt)?,)+);
+        const _: () = {
+            $($($crate::__compile_args_impl!(@assert_dynamic_pad $fmt $(; $width)?);)?)+
+        };
         $crate::CompileArgs::<__CAPACITY>::format(&[
-            $($crate::ArgumentWrapper::new($arg)$(.with_fmt($fmt))?.into_argument(),)+
+            $(
+                $crate::ArgumentWrapper::new($arg)$(.with_fmt($fmt))?.into_argument()
+                    $($(.with_pad_width($width))?)?,
+            )+
         ]) as $crate::CompileArgs<__CAPACITY>
         // ^ The type hint sometimes helps in const contexts
     }};
@@ -127,6 +168,397 @@ macro_rules! __compile_args_impl {
     (@arg_capacity $arg:expr => $fmt:expr) => {
         $crate::Fmt::capacity(&$fmt)
     };
+    (@assert_dynamic_pad $fmt:expr) => {};
+    (@assert_dynamic_pad $fmt:expr; $width:expr) => {
+        $crate::Fmt::assert_dynamic_pad(&$fmt)
+    };
+    (@assert_ascii $($arg:expr $(=> $fmt:expr)?,)+) => {
+        const _: () = {
+            $($crate::__compile_args_impl!(@assert_arg_ascii $arg $(=> $fmt)?);)+
+        };
+    };
+    (@assert_arg_ascii $arg:expr) => {
+        ::core::assert!(
+            $crate::ArgumentWrapper::new($arg).into_argument().is_ascii(),
+            "Constant argument is not ASCII"
+        )
+    };
+    (@assert_arg_ascii $arg:expr => $fmt:expr) => {
+        $crate::Fmt::assert_ascii(&$fmt)
+    };
+}
+
+/// Version of the [`compile_args!`] macro producing [`AsciiArgs`](crate::AsciiArgs).
+///
+/// Arguments have the same syntax as in the [`compile_args!`] macro. All arguments are checked
+/// in compile time to be provably ASCII:
+///
+/// - Constant arguments must consist of ASCII chars.
+/// - Dynamic arguments must have a format with 1 byte per char; i.e., be integers, [`Ascii`](crate::Ascii)
+///   strings or nested [`AsciiArgs`](crate::AsciiArgs). Padding chars must be ASCII as well.
+///
+/// # Examples
+///
+/// ```
+/// # use compile_fmt::{ascii_args, clip_ascii, fmt, Ascii, AsciiArgs};
+/// const fn format_id(name: Ascii<'_>, id: u32) -> AsciiArgs<24> {
+///     ascii_args!(capacity: 24, name => clip_ascii(8, "~"), '#', id => fmt::<u32>())
+/// }
+///
+/// let id = format_id(Ascii::new("test_entity"), 42);
+/// assert_eq!(id.as_str(), "test_ent~#42");
+/// ```
+///
+/// Non-ASCII arguments lead to a compilation error:
+///
+/// ```compile_fail
+/// # use compile_fmt::{ascii_args, fmt};
+/// const fn format_char(c: char) -> impl AsRef<str> {
+///     ascii_args!("char: ", c => fmt::<char>())
+/// }
+/// ```
+#[macro_export]
+macro_rules! ascii_args {
+    (capacity: $cap:expr, $($arg:expr $(=> $fmt:expr $(; $width:expr)?)?),+) => {{
+        $crate::__compile_args_impl!(@assert_ascii $($arg $(=> $fmt)?,)+);
+        $crate::AsciiArgs::new_unchecked(
+            $crate::compile_args!(capacity: $cap, $($arg $(=> $fmt $(; $width)?)?),+)
+        )
+    }};
+    ($($arg:expr $(=> $fmt:expr $(; $width:expr)?)?),+) => {{
+        $crate::__compile_args_impl!(@assert_ascii $($arg $(=> $fmt)?,)+);
+        $crate::AsciiArgs::new_unchecked($crate::compile_args!($($arg $(=> $fmt $(; $width)?)?),+))
+    }};
+}
+
+/// Concatenates several [`CompileArgs`](crate::CompileArgs), dynamic values and literals
+/// into a single `CompileArgs` instance.
+///
+/// Arguments to this macro must be comma-separated. Each argument must be either a literal
+/// (a string, char or integer), or an expression of a type with a known upper boundary
+/// for the formatted length (i.e., implementing [`MaxLength`](crate::MaxLength)); these are
+/// integers, `char`s, [`CompileArgs`](crate::CompileArgs) and [`AsciiArgs`](crate::AsciiArgs).
+/// Unlike [`compile_args!`], non-literal arguments may be dynamic (e.g., arguments of a `const fn`)
+/// and do not require specifying a format; their capacity is derived from their type.
+///
+/// # Specifying capacity
+///
+/// Since the capacity of the output cannot be computed from argument types on stable Rust,
+/// it is inferred from the context (e.g., the return type of a function, or an explicit type annotation).
+/// Alternatively, it can be specified explicitly by prefacing arguments with `capacity: $cap,`,
+/// similar to [`compile_args!`]. In any case, the capacity is checked in compile time
+/// to be sufficient for all possible argument values; with a tight capacity, it's equal
+/// to the sum of [`MaxLength`](crate::MaxLength)s of non-literal arguments and lengths of literals. Note that
+/// the check is only performed for code that is actually used (e.g., for `const fn`s that are called).
+///
+/// # Examples
+///
+/// ```
+/// use compile_fmt::{compile_args, concat_args, fmt, CompileArgs};
+///
+/// type CoordArgs = CompileArgs<13>;
+///
+/// const fn format_point(x: i32, y: i32) -> CompileArgs<30> {
+///     let x: CoordArgs = compile_args!("x=", x => fmt::<i32>());
+///     let y: CoordArgs = compile_args!("y=", y => fmt::<i32>());
+///     concat_args!('(', x, ", ", y, ')')
+///     // ^ 30 = 13 + 13 + 1 + 2 + 1
+/// }
+///
+/// assert_eq!(format_point(3, -42).as_str(), "(x=3, y=-42)");
+/// let args = concat_args!(capacity: 32, format_point(0, 0), '!');
+/// assert_eq!(args.as_str(), "(x=0, y=0)!");
+/// ```
+///
+/// Insufficient capacity will lead to a compilation error:
+///
+/// ```compile_fail
+/// # use compile_fmt::{compile_args, concat_args, fmt, CompileArgs};
+/// const fn format_point(x: i32, y: i32) -> CompileArgs<16> {
+///     let x: CompileArgs<13> = compile_args!("x=", x => fmt::<i32>());
+///     let y: CompileArgs<13> = compile_args!("y=", y => fmt::<i32>());
+///     concat_args!(x, ", ", y)
+/// }
+/// # format_point(1, 2);
+/// ```
+#[macro_export]
+macro_rules! concat_args {
+    (capacity: $cap:expr, $($arg:tt)+) => {{
+        const __CAPACITY: usize = $cap;
+        $crate::__concat_args_impl!(@munch [] [] [] [()] $($arg)+) as $crate::CompileArgs<__CAPACITY>
+    }};
+    ($($arg:tt)+) => {
+        $crate::__concat_args_impl!(@munch [] [] [] [()] $($arg)+)
+    };
+}
+
+#[doc(hidden)] // implementation detail of `concat_args`
+#[macro_export]
+macro_rules! __concat_args_impl {
+    // All arguments are processed. `__arg` bindings created in different recursion steps
+    // are distinct due to macro hygiene.
+    (
+        @munch [$($binding:tt)*] [$($part:tt)*] [$($literal_len:tt)*] [$dynamic_args:expr] $(,)?
+    ) => {{
+        const __LITERALS_CAPACITY: usize = 0 $($literal_len)*;
+        $($binding)*
+        $crate::CompileArgs::concat::<__LITERALS_CAPACITY, _>(&[$($part)*], &$dynamic_args)
+    }};
+    (
+        @munch [$($binding:tt)*] [$($part:tt)*] [$($literal_len:tt)*] [$dynamic_args:expr]
+        $lit:literal $(, $($rest:tt)*)?
+    ) => {
+        $crate::__concat_args_impl!(
+            @munch
+            [$($binding)*]
+            [$($part)* $crate::ArgumentWrapper::new($lit).into_argument(),]
+            [$($literal_len)* + $crate::ArgumentWrapper::new($lit).into_argument().formatted_len()]
+            [$dynamic_args]
+            $($($rest)*)?
+        )
+    };
+    (
+        @munch [$($binding:tt)*] [$($part:tt)*] [$($literal_len:tt)*] [$dynamic_args:expr]
+        $arg:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::__concat_args_impl!(
+            @munch
+            [$($binding)* let __arg = $arg;]
+            [$($part)* $crate::ArgumentWrapper::new(&__arg).into_argument(),]
+            [$($literal_len)*]
+            [(&__arg, $dynamic_args)]
+            $($($rest)*)?
+        )
+    };
+}
+
+/// Builds a compact JSON object in compile time.
+///
+/// The macro accepts a single object literal with comma-separated `$key: $value` entries.
+/// Keys must be literals; they are converted to strings (so that e.g. `1` is treated as `"1"`)
+/// and escaped. Values have the same syntax and restrictions as arguments of [`compile_args!`];
+/// i.e., a value may specify its [format](crate::Fmt) as `$value => $fmt`, and the format is mandatory
+/// for dynamic values. Values are converted to JSON as follows:
+///
+/// - Integers are output as is.
+/// - Strings, [`Ascii`](crate::Ascii) strings, `char`s, [`CompileArgs`](crate::CompileArgs)
+///   and [`AsciiArgs`](crate::AsciiArgs) are output as JSON strings, i.e. they are quoted
+///   and escaped using [`Fmt::json_str()`](crate::Fmt::json_str()). If the value format already calls
+///   `json_str()` explicitly (e.g., to clip the escaped string), it is used as is. Other quoting transforms
+///   (e.g., [`Fmt::debug()`](crate::Fmt::debug())) would produce invalid JSON and lead to a compilation error.
+///
+/// Padding specified in value formats is applied outside of string quotes. Since padding may produce
+/// invalid JSON (e.g., leading zeros for integers), it should not be used in most cases.
+///
+/// The value output by the macro is [`CompileArgs`](crate::CompileArgs). Its capacity can be specified
+/// as `capacity: $cap,` before the object, similar to [`compile_args!`]; otherwise, it is inferred
+/// from the entries.
+///
+/// # Examples
+///
+/// ```
+/// use compile_fmt::{clip, compile_json, fmt};
+///
+/// const fn status_frame(code: u32, msg: &str) -> impl AsRef<str> {
+///     compile_json!({ "code": code => fmt::<u32>(), "msg": msg => clip(16, "…") })
+/// }
+///
+/// let frame = status_frame(404, "\"/index\" not found");
+/// assert_eq!(frame.as_ref(), r#"{"code":404,"msg":"\"/index\" not fou…"}"#);
+///
+/// // Constant values do not require a format.
+/// let frame = compile_json!({ "id": 5_u8, "kind": "status", "ok": 'y' });
+/// assert_eq!(frame.as_str(), r#"{"id":5,"kind":"status","ok":"y"}"#);
+/// let empty = compile_json!(capacity: 8, {});
+/// assert_eq!(empty.as_str(), "{}");
+/// ```
+///
+/// Insufficient specified capacity will lead to a compilation error:
+///
+/// ```compile_fail
+/// # use compile_fmt::compile_json;
+/// let frame = compile_json!(capacity: 8, { "code": 42 });
+/// ```
+#[macro_export]
+macro_rules! compile_json {
+    (capacity: $cap:expr, { $($entries:tt)* }) => {{
+        const __CAPACITY: usize = $cap;
+        const _: () = {
+            let required_capacity = $crate::__compile_json_impl!(@total_capacity $($entries)*);
+            $crate::CompileArgs::<__CAPACITY>::assert_capacity_for("compile_json", required_capacity);
+        };
+        $crate::__compile_json_impl!(@format __CAPACITY; $($entries)*)
+    }};
+    ({ $($entries:tt)* }) => {{
+        const __CAPACITY: usize = $crate::__compile_json_impl!(@total_capacity $($entries)*);
+        $crate::__compile_json_impl!(@format __CAPACITY; $($entries)*)
+    }};
+}
+
+#[doc(hidden)] // implementation detail of `compile_json`
+#[macro_export]
+macro_rules! __compile_json_impl {
+    (@total_capacity) => {
+        2 // `{}`
+    };
+    (
+        @total_capacity
+        $first_key:literal : $first_value:expr $(=> $first_fmt:expr)?
+        $(, $key:literal : $value:expr $(=> $fmt:expr)?)* $(,)?
+    ) => {
+        // `{` / `,` preceding each entry + `:` in each entry + closing `}`
+        $crate::__compile_json_impl!(@entry_capacity $first_key : $first_value $(=> $first_fmt)?)
+            $(+ $crate::__compile_json_impl!(@entry_capacity $key : $value $(=> $fmt)?))* + 1
+    };
+    (@entry_capacity $key:literal : $value:expr $(=> $fmt:expr)?) => {
+        2 + $crate::__compile_json_impl!(@key $key).formatted_len()
+            + $crate::__compile_json_impl!(@value_capacity $value $(=> $fmt)?)
+    };
+    (@value_capacity $value:expr) => {
+        $crate::__compile_json_impl!(@value $value).formatted_len()
+    };
+    (@value_capacity $value:expr => $fmt:expr) => {
+        $crate::Fmt::capacity(&$fmt.json_value())
+    };
+
+    (@format $cap:ident;) => {
+        $crate::CompileArgs::<$cap>::format(&[
+            $crate::ArgumentWrapper::new("{}").into_argument(),
+        ]) as $crate::CompileArgs<$cap>
+    };
+    (
+        @format $cap:ident;
+        $first_key:literal : $first_value:expr $(=> $first_fmt:expr)?
+        $(, $key:literal : $value:expr $(=> $fmt:expr)?)* $(,)?
+    ) => {
+        $crate::CompileArgs::<$cap>::format(&[
+            $crate::ArgumentWrapper::new("{").into_argument(),
+            $crate::__compile_json_impl!(@key $first_key),
+            $crate::ArgumentWrapper::new(":").into_argument(),
+            $crate::__compile_json_impl!(@value $first_value $(=> $first_fmt)?),
+            $(
+                $crate::ArgumentWrapper::new(",").into_argument(),
+                $crate::__compile_json_impl!(@key $key),
+                $crate::ArgumentWrapper::new(":").into_argument(),
+                $crate::__compile_json_impl!(@value $value $(=> $fmt)?),
+            )*
+            $crate::ArgumentWrapper::new("}").into_argument(),
+        ]) as $crate::CompileArgs<$cap>
+        // ^ The type hint sometimes helps in const contexts
+    };
+
+    (@key $key:literal) => {
+        $crate::ArgumentWrapper::new(concat!($key)).json_value().into_argument()
+    };
+    (@value $value:expr) => {
+        $crate::ArgumentWrapper::new($value).json_value().into_argument()
+    };
+    (@value $value:expr => $fmt:expr) => {
+        $crate::ArgumentWrapper::new($value).with_fmt($fmt.json_value()).into_argument()
+    };
+}
+
+/// Builds a [logfmt]-style line of `key=value` pairs in compile time.
+///
+/// The macro accepts comma-separated `$key = $value` entries, where keys are identifiers output as is.
+/// Values have the same syntax and restrictions as arguments of [`compile_args!`]; i.e.,
+/// a value may specify its [format](crate::Fmt) as `$value => $fmt`, and the format is mandatory
+/// for dynamic values. Values are converted as follows:
+///
+/// - Integers are output as is.
+/// - Strings, [`Ascii`](crate::Ascii) strings, `char`s, [`CompileArgs`](crate::CompileArgs)
+///   and [`AsciiArgs`](crate::AsciiArgs) are output as is if they are non-empty and don't contain whitespace
+///   (including non-ASCII whitespace, e.g. U+00A0 no-break space), `=`, `"`, `\` or ASCII control chars. Otherwise, they are quoted and escaped in the same way
+///   as JSON strings (see [`Fmt::json_str()`](crate::Fmt::json_str())). Quoting is decided based
+///   on the entire string and the clipping replacer, so a clipped string may be quoted even if
+///   its retained part could be output as is. If the value format is already quoted, it is used as is.
+///
+/// The value output by the macro is [`CompileArgs`](crate::CompileArgs). Its capacity can be specified
+/// as `capacity: $cap,` before the entries, similar to [`compile_args!`]; otherwise, it is inferred
+/// from the entries. The inferred capacity always accounts for quoting and escaping of string values.
+///
+/// [logfmt]: https://brandur.org/logfmt
+///
+/// # Examples
+///
+/// ```
+/// use compile_fmt::{clip, compile_kv, fmt};
+///
+/// const fn log_line(code: u16, path: &str) -> impl AsRef<str> {
+///     compile_kv!(level = "error", code = code => fmt::<u16>(), path = path => clip(32, "…"))
+/// }
+///
+/// let line = log_line(42, "a b");
+/// assert_eq!(line.as_ref(), r#"level=error code=42 path="a b""#);
+/// let line = log_line(404, "/index.html");
+/// assert_eq!(line.as_ref(), "level=error code=404 path=/index.html");
+/// let line = log_line(400, "C:\\Windows");
+/// assert_eq!(line.as_ref(), r#"level=error code=400 path="C:\\Windows""#);
+/// ```
+///
+/// Insufficient specified capacity will lead to a compilation error:
+///
+/// ```compile_fail
+/// # use compile_fmt::compile_kv;
+/// let line = compile_kv!(capacity: 8, level = "error");
+/// ```
+#[macro_export]
+macro_rules! compile_kv {
+    (capacity: $cap:expr, $($key:ident = $value:expr $(=> $fmt:expr)?),+ $(,)?) => {{
+        const __CAPACITY: usize = $cap;
+        const _: () = {
+            let required_capacity =
+                $crate::__compile_kv_impl!(@total_capacity $($key = $value $(=> $fmt)?,)+);
+            $crate::CompileArgs::<__CAPACITY>::assert_capacity_for("compile_kv", required_capacity);
+        };
+        $crate::__compile_kv_impl!(@format __CAPACITY; $($key = $value $(=> $fmt)?,)+)
+    }};
+    ($($key:ident = $value:expr $(=> $fmt:expr)?),+ $(,)?) => {{
+        const __CAPACITY: usize =
+            $crate::__compile_kv_impl!(@total_capacity $($key = $value $(=> $fmt)?,)+);
+        $crate::__compile_kv_impl!(@format __CAPACITY; $($key = $value $(=> $fmt)?,)+)
+    }};
+}
+
+#[doc(hidden)] // implementation detail of `compile_kv`
+#[macro_export]
+macro_rules! __compile_kv_impl {
+    (@total_capacity $($key:ident = $value:expr $(=> $fmt:expr)?,)+) => {
+        // `=` in each entry + ` ` separating entries
+        0 $(
+            + stringify!($key).len() + 2
+            + $crate::__compile_kv_impl!(@value_capacity $value $(=> $fmt)?)
+        )+ - 1
+    };
+    (@value_capacity $value:expr) => {
+        $crate::__compile_kv_impl!(@value $value).formatted_len()
+    };
+    (@value_capacity $value:expr => $fmt:expr) => {
+        $crate::Fmt::capacity(&$fmt.kv_value())
+    };
+
+    (
+        @format $cap:ident;
+        $first_key:ident = $first_value:expr $(=> $first_fmt:expr)?,
+        $($key:ident = $value:expr $(=> $fmt:expr)?,)*
+    ) => {
+        $crate::CompileArgs::<$cap>::format(&[
+            $crate::ArgumentWrapper::new(concat!(stringify!($first_key), "=")).into_argument(),
+            $crate::__compile_kv_impl!(@value $first_value $(=> $first_fmt)?),
+            $(
+                $crate::ArgumentWrapper::new(concat!(" ", stringify!($key), "=")).into_argument(),
+                $crate::__compile_kv_impl!(@value $value $(=> $fmt)?),
+            )*
+        ]) as $crate::CompileArgs<$cap>
+        // ^ The type hint sometimes helps in const contexts
+    };
+
+    (@value $value:expr) => {
+        $crate::ArgumentWrapper::new($value).kv_value().into_argument()
+    };
+    (@value $value:expr => $fmt:expr) => {
+        $crate::ArgumentWrapper::new($value).with_fmt($fmt.kv_value()).into_argument()
+    };
 }
 
 /// Version of the [`panic!`] macro with the ability to format args in compile time.
@@ -0,0 +1,95 @@
+//! Sets of chars for URL percent-encoding.
+
+/// Set of ASCII chars that are [percent-encoded] by [`Fmt::percent_encode()`](crate::Fmt::percent_encode()).
+/// Non-ASCII chars are always encoded as a sequence of `%XX` triplets for each byte of their UTF-8 encoding.
+///
+/// Predefined sets are based on the component grammar in [RFC 3986]. They are meant to encode
+/// a raw (i.e., not yet encoded) URL component, so `%` is encoded by all of them. Unreserved chars
+/// (ASCII letters and digits, `-`, `.`, `_` and `~`) are never encoded.
+///
+/// [percent-encoded]: https://www.rfc-editor.org/rfc/rfc3986#section-2.1
+/// [RFC 3986]: https://www.rfc-editor.org/rfc/rfc3986#section-3
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PercentEncodeSet(SetKind);
+
+/// Predefined set kinds. Storing a kind rather than a bit mask keeps string formats compact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SetKind {
+    PathSegment,
+    Query,
+    Userinfo,
+}
+
+impl PercentEncodeSet {
+    /// Set for a single path segment. Retains unreserved chars, sub-delimiters (`!$&'()*+,;=`), `:` and `@`;
+    /// in particular, `/` is encoded.
+    pub const PATH_SEGMENT: Self = Self(SetKind::PathSegment);
+
+    /// Set for a name or a value in the query string. Retains unreserved chars and `!$'()*,;:@/?`;
+    /// in particular, `&`, `=`, `+` and `#` are encoded.
+    pub const QUERY: Self = Self(SetKind::Query);
+
+    /// Set for a username or password in the userinfo. Retains unreserved chars and sub-delimiters
+    /// (`!$&'()*+,;=`); in particular, `:` and `@` are encoded.
+    pub const USERINFO: Self = Self(SetKind::Userinfo);
+
+    /// Checks whether the specified char is encoded by this set.
+    pub(crate) const fn encodes(self, c: char) -> bool {
+        if !c.is_ascii() {
+            return true;
+        }
+        let mask = match self.0 {
+            SetKind::PathSegment => PATH_SEGMENT_MASK,
+            SetKind::Query => QUERY_MASK,
+            SetKind::Userinfo => USERINFO_MASK,
+        };
+        mask & (1 << c as u32) != 0
+    }
+}
+
+const UNRESERVED: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~";
+const SUB_DELIMITERS: &[u8] = b"!$&'()*+,;=";
+
+/// Bit masks with bits set for encoded ASCII chars.
+const ALL_MASK: u128 = retain(u128::MAX, UNRESERVED);
+const PATH_SEGMENT_MASK: u128 = retain(retain(ALL_MASK, SUB_DELIMITERS), b":@");
+const QUERY_MASK: u128 = retain(ALL_MASK, b"!$'()*,;:@/?");
+const USERINFO_MASK: u128 = retain(ALL_MASK, SUB_DELIMITERS);
+
+const fn retain(mut mask: u128, bytes: &[u8]) -> u128 {
+    let mut i = 0;
+    while i < bytes.len() {
+        mask &= !(1 << bytes[i]);
+        i += 1;
+    }
+    mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predefined_sets() {
+        for c in "aZ09-._~".chars() {
+            assert!(!PercentEncodeSet::PATH_SEGMENT.encodes(c), "{c:?}");
+            assert!(!PercentEncodeSet::QUERY.encodes(c), "{c:?}");
+            assert!(!PercentEncodeSet::USERINFO.encodes(c), "{c:?}");
+        }
+        for c in " %\"#<>[]\\^`{|}\0\n\u{7f}ß".chars() {
+            assert!(PercentEncodeSet::PATH_SEGMENT.encodes(c), "{c:?}");
+            assert!(PercentEncodeSet::QUERY.encodes(c), "{c:?}");
+            assert!(PercentEncodeSet::USERINFO.encodes(c), "{c:?}");
+        }
+
+        let set = PercentEncodeSet::PATH_SEGMENT;
+        assert!(set.encodes('/') && set.encodes('?'));
+        assert!(!set.encodes(':') && !set.encodes('@') && !set.encodes('='));
+        let set = PercentEncodeSet::QUERY;
+        assert!(set.encodes('&') && set.encodes('=') && set.encodes('+'));
+        assert!(!set.encodes('/') && !set.encodes('?') && !set.encodes(':'));
+        let set = PercentEncodeSet::USERINFO;
+        assert!(set.encodes(':') && set.encodes('@') && set.encodes('/'));
+        assert!(!set.encodes('&') && !set.encodes('='));
+    }
+}
@@ -1,6 +1,7 @@
 //! General-purpose tests.
 
 use std::{
+    collections::{BTreeSet, HashSet},
     panic,
     string::{String, ToString},
 };
@@ -16,6 +17,159 @@ fn basics() {
     assert_eq!(TEST.to_string(), "expected 1 to be greater than 32");
 }
 
+#[test]
+fn unsuffixed_integer_literals() {
+    const ARGS: CompileArgs<28> = compile_args!(1, " < ", 2_147_483_647, " > ", -2_147_483_648);
+    assert_eq!(ARGS, "1 < 2147483647 > -2147483648");
+
+    let args = concat_args!(capacity: 4, 4, 2, '!');
+    assert_eq!(args, "42!");
+}
+
+#[test]
+fn comparing_args() {
+    const ARGS: CompileArgs<32> = compile_args!(capacity: 32, "expected ", 1_usize);
+    let other_args: CompileArgs<12> = compile_args!(capacity: 12, "expected ", 1_usize);
+    // Unused buffer bytes must not influence comparisons.
+    let garbage_args =
+        CompileArgs::<32>::format(&[ArgumentWrapper::new("expected 1 and more").into_argument()]);
+
+    assert_eq!(ARGS, "expected 1");
+    assert_eq!(ARGS, *"expected 1");
+    assert_eq!(ARGS, other_args);
+    assert_ne!(ARGS, garbage_args);
+    assert!(ARGS < garbage_args);
+    assert_eq!(ARGS.len(), 10);
+    assert!(!ARGS.is_empty());
+    assert_eq!(ARGS.as_bytes(), b"expected 1");
+
+    let mut truncated = garbage_args;
+    truncated.len = ARGS.len();
+    assert_eq!(truncated, ARGS);
+    assert_eq!(truncated.cmp(&ARGS), core::cmp::Ordering::Equal);
+    let set: HashSet<_> = [ARGS, truncated].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    assert!(set.contains("expected 1"));
+    let set: BTreeSet<_> = [ARGS, truncated, garbage_args].into_iter().collect();
+    assert_eq!(set.len(), 2);
+
+    let empty = CompileArgs::<16>::default();
+    assert!(empty.is_empty());
+    assert_eq!(empty, "");
+}
+
+#[test]
+fn comparing_args_in_const_context() {
+    const ARGS: CompileArgs<32> = compile_args!(capacity: 32, "expected ", 1_usize, " to be ß");
+    const _: () = assert!(ARGS.eq_str("expected 1 to be ß"));
+    const _: () = assert!(!ARGS.eq_str("expected 1"));
+    const _: () = assert!(ARGS.starts_with_str("expected 1"));
+    const _: () = assert!(!ARGS.starts_with_str("to be"));
+    const _: () = assert!(ARGS.ends_with_str("to be ß"));
+    const _: () = assert!(!ARGS.ends_with_str("expected"));
+    const _: () = assert!(ARGS.contains_str("1 to"));
+    const _: () = assert!(ARGS.contains_str(""));
+    const _: () = assert!(!ARGS.contains_str("2 to"));
+
+    let garbage_args =
+        CompileArgs::<32>::format(&[ArgumentWrapper::new("expected 1 and more").into_argument()]);
+    let mut truncated = garbage_args;
+    truncated.len = "expected 1".len();
+    assert!(truncated.eq_str("expected 1"));
+    assert!(!truncated.contains_str("and"));
+    assert!(!truncated.ends_with_str("1 and"));
+
+    // `str` methods are still available via `Deref` and accept any patterns.
+    assert!(ARGS.starts_with(char::is_alphabetic));
+    assert!(ARGS.contains('ß'));
+    assert!(!truncated.ends_with(['d', 'e']));
+}
+
+#[test]
+fn converting_capacity() {
+    let value = 42_u32;
+    let args = compile_args!("value: ", value => fmt::<u32>());
+    assert_eq!(args.capacity(), 17);
+
+    let widened: CompileArgs<32> = args.widen();
+    assert_eq!(widened, "value: 42");
+    assert_eq!(widened.capacity(), 32);
+
+    let shrunk: CompileArgs<9> = widened.try_shrink().unwrap();
+    assert_eq!(shrunk, "value: 42");
+    assert!(widened.try_shrink::<8>().is_none());
+
+    let resized: CompileArgs<10> = args.resize();
+    assert_eq!(resized, "value: 42");
+    let resized: CompileArgs<20> = args.resize();
+    assert_eq!(resized, "value: 42");
+}
+
+#[test]
+#[should_panic(expected = "Cannot resize arguments with length 9 bytes to capacity 4 bytes")]
+fn resize_panic() {
+    let args = compile_args!("value: ", 42_u32 => fmt::<u32>());
+    args.resize::<4>();
+}
+
+#[test]
+fn concatenating_args() {
+    const fn format_value(value: u8) -> CompileArgs<9> {
+        compile_args!("value=", value => fmt::<u8>())
+    }
+
+    const fn format_values(first: u8, second: u8) -> CompileArgs<23> {
+        concat_args!('[', format_value(first), ", ", format_value(second), "]!")
+    }
+
+    const VALUES: CompileArgs<23> = format_values(1, 255);
+    assert_eq!(VALUES, "[value=1, value=255]!");
+
+    let first = format_value(42);
+    let args = concat_args!(capacity: 31, first, 7_u8, VALUES.resize::<21>(),);
+    assert_eq!(args, "value=427[value=1, value=255]!");
+    let args: CompileArgs<20> = concat_args!(first, first);
+    assert_eq!(args, "value=42value=42");
+}
+
+#[test]
+fn concatenating_dynamic_values() {
+    const fn format_pair(key: char, value: i16, flag: u8) -> CompileArgs<18> {
+        concat_args!(key, " = ", value, "; ", flag)
+        // ^ 18 = 4 + 3 + 6 + 2 + 3
+    }
+
+    assert_eq!(format_pair('ß', -1_234, 255), "ß = -1234; 255");
+    let pair = format_pair('x', 0, 1);
+    let flag = 32_u8;
+    let args: CompileArgs<21> = concat_args!(pair, flag);
+    assert_eq!(args, "x = 0; 132");
+
+    let value = 42_u64;
+    let args = compile_args!("[", &value => fmt::<&u64>().pad_right(4, ' '), "]");
+    assert_eq!(args, "[  42]");
+}
+
+#[test]
+fn writing_args_in_runtime() {
+    use core::fmt::Write;
+
+    // Inherent `write_*` methods shadow `Write` ones, hence the qualified calls.
+    let mut args = CompileArgs::<8>::default();
+    write!(args, "{}", 42).unwrap();
+    Write::write_char(&mut args, 'ß').unwrap();
+    assert_eq!(args, "42ß");
+    assert!(Write::write_str(&mut args, "💣💣").is_err());
+    assert_eq!(args, "42ß💣");
+    assert!(Write::write_char(&mut args, '!').is_err());
+    assert_eq!(args, "42ß💣");
+
+    let args = CompileArgs::<11>::from_fmt(format_args!("{:>4}|{:<4}", 'ℝ', 1)).unwrap();
+    assert_eq!(args, "   ℝ|1   ");
+    let err = CompileArgs::<10>::from_fmt(format_args!("{:>4}|{:<4}", 'ℝ', 1)).unwrap_err();
+    assert_eq!(err, core::fmt::Error);
+}
+
 #[test]
 fn using_chars() {
     const CHARS: CompileArgs<11> = compile_args!('H', 'i', 'ß', 'ℝ', '💣');
@@ -61,6 +215,423 @@ fn clipping_strings_with_clip_chars() {
     assert_eq!(s.as_str(), "string: 'dynamic'");
 }
 
+#[test]
+fn clipping_strings_from_start() {
+    let arg = "Tℝ💣eßt";
+    let s = compile_args!("string: '", arg => clip_start(2, ""), '\'');
+    assert_eq!(s.as_str(), "string: 'ßt'");
+    let s = compile_args!("string: '", arg => clip_start(4, "…"), '\'');
+    assert_eq!(s.as_str(), "string: '…💣eßt'");
+    let s = compile_args!("string: '", arg => clip_start(6, "…"), '\'');
+    assert_eq!(s.as_str(), "string: 'Tℝ💣eßt'");
+
+    let s = compile_args!(
+        "[", arg => clip_start(3, "..").pad_right(6, ' '), "]"
+    );
+    assert_eq!(s.as_str(), "[ ..eßt]");
+}
+
+#[test]
+fn eliding_middle_of_strings() {
+    let arg = "Tℝ💣eßt";
+    let s = compile_args!("string: '", arg => elide_middle(2, 2, "…"), '\'');
+    assert_eq!(s.as_str(), "string: 'Tℝ…ßt'");
+    let s = compile_args!("string: '", arg => elide_middle(1, 0, "~"), '\'');
+    assert_eq!(s.as_str(), "string: 'T~'");
+    let s = compile_args!("string: '", arg => elide_middle(0, 1, ""), '\'');
+    assert_eq!(s.as_str(), "string: 't'");
+    let s = compile_args!("string: '", arg => elide_middle(3, 3, "…"), '\'');
+    assert_eq!(s.as_str(), "string: 'Tℝ💣eßt'");
+
+    let s: CompileArgs<10> = compile_args!(
+        "[", Ascii::new("0x1234567890") => elide_middle_ascii(4, 2, ".."), "]"
+    );
+    assert_eq!(s.as_str(), "[0x12..90]");
+    let s: CompileArgs<7> = compile_args!(
+        "[", Ascii::new("0x1234567890") => clip_start_ascii(4, "~"), "]"
+    );
+    assert_eq!(s.as_str(), "[~7890]");
+}
+
+#[test]
+fn clipping_strings_by_bytes() {
+    let arg = "Tℝ💣eßt";
+    let s = compile_args!("string: '", arg => clip_bytes(3, ""), '\'');
+    assert_eq!(s.as_str(), "string: 'T'");
+    let s = compile_args!("string: '", arg => clip_bytes(4, "…"), '\'');
+    assert_eq!(s.as_str(), "string: 'Tℝ…'");
+    let s = compile_args!("string: '", arg => clip_bytes(13, "…"), '\'');
+    assert_eq!(s.as_str(), "string: 'Tℝ💣eßt'");
+
+    let s: CompileArgs<13> = compile_args!("[", arg => clip_bytes(8, "…"), "]");
+    // ^ 13 = 1 + 8 + "…".len() + 1
+    assert_eq!(s.as_str(), "[Tℝ💣…]");
+}
+
+#[test]
+fn clipping_strings_at_word_boundaries() {
+    let arg = "Tℝ💣 eßt, test";
+    let s = compile_args!("[", arg => clip_words(5, "…"), "]");
+    assert_eq!(s.as_str(), "[Tℝ💣…]");
+    let s = compile_args!("[", arg => clip_words(9, "…"), "]");
+    assert_eq!(s.as_str(), "[Tℝ💣 eßt,…]");
+    let s = compile_args!("[", arg => clip_words(2, "…"), "]");
+    assert_eq!(s.as_str(), "[Tℝ…]");
+    let s = compile_args!("[", arg => clip_words(13, "…"), "]");
+    assert_eq!(s.as_str(), "[Tℝ💣 eßt, test]");
+
+    let s = compile_args!("[", arg => clip_words(11, "…").with_omitted_count(), "]");
+    assert_eq!(s.as_str(), "[Tℝ💣 eßt,… (+5 chars)]");
+}
+
+#[test]
+fn clipping_strings_by_grapheme_clusters() {
+    let arg = "🇺🇸👩🏽‍🚒e\u{301}t";
+    let s = compile_args!("[", arg => clip_graphemes(1, "…"), "]");
+    assert_eq!(s.as_str(), "[🇺🇸…]");
+    let s = compile_args!("[", arg => clip_graphemes(2, "…"), "]");
+    assert_eq!(s.as_str(), "[🇺🇸👩🏽‍🚒…]");
+    let s = compile_args!("[", arg => clip_graphemes(3, "…"), "]");
+    assert_eq!(s.as_str(), "[🇺🇸👩🏽‍🚒e\u{301}…]");
+    let s = compile_args!("[", arg => clip_graphemes(4, "…"), "]");
+    assert_eq!(s.as_str(), std::format!("[{arg}]"));
+
+    let s = compile_args!("[", arg => clip_graphemes(3, "…").with_omitted_count(), "]");
+    assert_eq!(s.as_str(), "[🇺🇸👩🏽‍🚒e\u{301}… (+1 char)]");
+}
+
+#[test]
+fn clipping_strings_with_omitted_count() {
+    let arg = "Tℝ💣eßt";
+    let s = compile_args!("[", arg => clip(2, "…").with_omitted_count(), "]");
+    assert_eq!(s.as_str(), "[Tℝ… (+4 chars)]");
+    let s = compile_args!("[", arg => elide_middle(1, 2, "…").with_omitted_count(), "]");
+    assert_eq!(s.as_str(), "[T…ßt (+3 chars)]");
+    let s = compile_args!("[", arg => clip_bytes(4, "…").with_omitted_count(), "]");
+    assert_eq!(s.as_str(), "[Tℝ… (+8 bytes)]");
+    let s = compile_args!("[", arg => clip(6, "…").with_omitted_count(), "]");
+    assert_eq!(s.as_str(), "[Tℝ💣eßt]");
+
+    let long_arg = "-".repeat(1_000);
+    let s = compile_args!("[", long_arg.as_str() => clip_start(1, "").with_omitted_count(), "]");
+    assert_eq!(s.as_str(), "[- (+999 chars)]");
+
+    let nested = compile_args!(capacity: 16, "value: ", 12_345_u32);
+    let s = compile_args!(
+        "[", &nested => fmt::<&CompileArgs<16>>().clip(5, "").with_omitted_count(), "]"
+    );
+    assert_eq!(s.as_str(), "[value (+7 chars)]");
+
+    let s = compile_args!("[", arg => clip(5, "").with_omitted_count(), "]");
+    assert_eq!(s.as_str(), "[Tℝ💣eß (+1 char)]");
+    let s = compile_args!("[", arg => clip_bytes(11, "").with_omitted_count(), "]");
+    assert_eq!(s.as_str(), "[Tℝ💣eß (+1 byte)]");
+}
+
+#[test]
+fn transforming_strings() {
+    let arg = "Tℝ💣eßt str";
+    let s = compile_args!("[", arg => clip(4, "…").to_ascii_uppercase(), "]");
+    assert_eq!(s.as_str(), "[Tℝ💣E…]");
+    let s = compile_args!("[", arg => str_fmt().to_ascii_lowercase().clip(4, "_X"), "]");
+    assert_eq!(s.as_str(), "[tℝ💣e_X]");
+    let s = compile_args!("[", arg => clip(4, "_x").to_ascii_uppercase(), "]");
+    assert_eq!(s.as_str(), "[Tℝ💣E_X]");
+    let s = compile_args!(
+        "[", arg => str_fmt().to_ascii_uppercase().clip(32, "…").to_ascii_lowercase(), "]"
+    );
+    assert_eq!(s.as_str(), "[tℝ💣eßt str]");
+
+    let s = compile_args!(
+        "[", arg => clip(3, "…").with_omitted_count().to_ascii_uppercase(), "]"
+    );
+    assert_eq!(s.as_str(), "[Tℝ💣… (+7 CHARS)]");
+    let s = compile_args!(
+        "[", arg => str_fmt().to_ascii_uppercase().clip(3, "…").with_omitted_count(), "]"
+    );
+    assert_eq!(s.as_str(), "[Tℝ💣… (+7 chars)]");
+
+    let nested = compile_args!(capacity: 16, "value: ", 12_345_u32);
+    let s = compile_args!(
+        "[", &nested => fmt::<&CompileArgs<16>>().to_ascii_uppercase().pad_left(14, ' '), "]"
+    );
+    assert_eq!(s.as_str(), "[VALUE: 12345  ]");
+}
+
+#[test]
+fn transforming_ascii_strings() {
+    let arg = "tℝ💣eßt str";
+    let s = compile_args!(
+        "[", arg => str_fmt().to_ascii_uppercase().clip(2, "…").pad_left(4, '-'), "]"
+    );
+    assert_eq!(s.as_str(), "[Tℝ…-]");
+
+    let arg = Ascii::new("hello, world");
+    let s = compile_args!("[", arg => clip_start_ascii(5, "..").to_ascii_uppercase(), "]");
+    assert_eq!(s.as_str(), "[..WORLD]");
+    let s = ascii_args!("[", arg => elide_middle_ascii(2, 3, "~").to_ascii_uppercase(), "]");
+    assert_eq!(s.as_str(), "[HE~RLD]");
+}
+
+#[test]
+fn debug_formatting_strings() {
+    let strings = [
+        "",
+        "test",
+        "line\nbreak\ttab\r",
+        "\"quoted\" 'single'",
+        "back\\slash \0 \u{7f} \u{1b}[0m",
+        "Tℝ💣eßt",
+        "Zoe\u{308} e\u{301}",
+        "\u{a0}\u{200b}\u{feff}\u{e000}",
+        "👩🏽‍🚒 🇺🇸",
+    ];
+    for s in strings {
+        let formatted = compile_args!(s => clip(64, "").debug());
+        assert_eq!(formatted.as_str(), std::format!("{s:?}"));
+        let formatted = compile_args!(s => str_fmt().debug().clip(128, ""));
+        assert_eq!(formatted.as_str(), std::format!("{s:?}"));
+    }
+}
+
+#[test]
+fn debug_formatting_chars() {
+    let chars = [
+        'a', ' ', '\n', '\'', '"', '\\', '\0', 'ß', '💣', '\u{301}', '\u{200d}',
+    ];
+    for c in chars {
+        let formatted = compile_args!(c => fmt::<char>().debug());
+        assert_eq!(formatted.as_str(), std::format!("{c:?}"));
+        let formatted = compile_args!(&c => fmt::<&char>().debug().pad_left(12, '.'));
+        assert_eq!(
+            formatted.as_str(),
+            std::format!("{:.<12}", std::format!("{c:?}"))
+        );
+    }
+}
+
+#[test]
+fn debug_formatting_with_clipping() {
+    let arg = "\u{7f}ab\ncd";
+    let s = compile_args!("[", arg => clip(3, "…").debug(), "]");
+    assert_eq!(s.as_str(), r#"["\u{7f}ab…"]"#);
+    let s = compile_args!("[", arg => clip(3, "…").with_omitted_count().debug(), "]");
+    assert_eq!(s.as_str(), r#"["\u{7f}ab… (+3 chars)"]"#);
+
+    // Escape sequences are never split.
+    let s = compile_args!("[", arg => str_fmt().debug().clip(3, "…"), "]");
+    assert_eq!(s.as_str(), r#"["…"]"#);
+    // The omitted count refers to chars in the original string.
+    let s = compile_args!("[", arg => str_fmt().debug().clip(8, "…").with_omitted_count(), "]");
+    assert_eq!(s.as_str(), r#"["\u{7f}ab… (+3 chars)"]"#);
+    let s = compile_args!("[", arg => str_fmt().debug().clip(10, "…"), "]");
+    assert_eq!(s.as_str(), r#"["\u{7f}ab\n…"]"#);
+
+    let arg = Ascii::new("key=\"value\"");
+    let s = ascii_args!("[", arg => clip_ascii(8, "..").debug(), "]");
+    assert_eq!(s.as_str(), r#"["key=\"val.."]"#);
+}
+
+#[test]
+fn json_strings() {
+    let arg = "say \"hi\"\n\t\\ \u{1}\u{1f} ß💣";
+    let s = compile_args!("{\"msg\":", arg => clip(32, "…").json_str(), "}");
+    assert_eq!(s.as_str(), r#"{"msg":"say \"hi\"\n\t\\ \u0001\u001f ß💣"}"#);
+
+    let arg = Ascii::new("a\"b\u{8}\u{c}\r");
+    let s = ascii_args!(arg => clip_ascii(8, "").json_str());
+    assert_eq!(s.as_str(), r#""a\"b\b\f\r""#);
+    let s = compile_args!('"' => fmt::<char>().json_str());
+    assert_eq!(s.as_str(), r#""\"""#);
+}
+
+#[test]
+fn json_strings_with_clipping() {
+    let arg = "a\"b\\c\u{1}d";
+    let escaped = r#"a\"b\\c\u0001d"#;
+    let boundaries = [0, 1, 3, 4, 6, 7, 13, 14];
+
+    for clip_at in 1..=16 {
+        let format = str_fmt().json_str().clip(clip_at, "…");
+        let arg = ArgumentWrapper::new(arg).with_fmt(format).into_argument();
+        let s = CompileArgs::<64>::format(&[arg]);
+        let s = s.as_str();
+        assert!(s.starts_with('"') && s.ends_with('"'), "{s}");
+        let content = &s[1..s.len() - 1];
+        let head = content.strip_suffix('…').unwrap_or(content);
+        assert!(escaped.starts_with(head), "{s}");
+        assert!(boundaries.contains(&head.len()), "{s}");
+        assert!(head.len() <= clip_at);
+    }
+}
+
+#[test]
+fn escaping_replacer_after_quoting() {
+    let s = compile_args!("\"a\tb\"" => str_fmt().json_str().clip(4, "\""));
+    assert_eq!(s.as_str(), r#""\"a\"""#);
+    let s = compile_args!("a\nb" => str_fmt().json_str().clip(1, "\\\n"));
+    assert_eq!(s.as_str(), r#""a\\\n""#);
+    let s = compile_args!("a\nb" => str_fmt().debug().clip(1, "\"\u{301}"));
+    assert_eq!(s.as_str(), r#""a\"\u{301}""#);
+}
+
+#[test]
+fn percent_encoding() {
+    const fn provisioning_url(user: &str, device: &str, query: &str) -> CompileArgs<272> {
+        compile_args!(
+            "https://",
+            user => clip(8, "").percent_encode(PercentEncodeSet::USERINFO),
+            "@example.com/devices/",
+            device => clip(8, "").percent_encode(PercentEncodeSet::PATH_SEGMENT),
+            "?q=",
+            query => percent_encode(PercentEncodeSet::QUERY).clip(12, "")
+        )
+    }
+
+    let url = provisioning_url("user", "sensor-1", "a");
+    assert_eq!(
+        url.as_str(),
+        "https://user@example.com/devices/sensor-1?q=a"
+    );
+    let url = provisioning_url("a:b@c", "1/2 ?", "x=1&y=ℝ");
+    assert_eq!(
+        url.as_str(),
+        "https://a%3Ab%40c@example.com/devices/1%2F2%20%3F?q=x%3D1%26y%3D"
+    );
+    let url = provisioning_url("💣", "тест тест", "/path?a b");
+    assert_eq!(
+        url.as_str(),
+        "https://%F0%9F%92%A3@example.com/devices/%D1%82%D0%B5%D1%81%D1%82%20%D1%82%D0%B5%D1%81\
+         ?q=/path?a%20b"
+    );
+
+    let s = compile_args!(
+        "[", Ascii::new("a b") => clip_ascii(3, "").percent_encode(PercentEncodeSet::QUERY), "]"
+    );
+    assert_eq!(s.as_str(), "[a%20b]");
+}
+
+#[test]
+fn percent_encoding_with_clipping() {
+    const SET: PercentEncodeSet = PercentEncodeSet::QUERY;
+
+    let s = compile_args!("?q=", "a b&c" => percent_encode(SET).clip(4, "…").with_omitted_count());
+    // The replacer and the omitted count are encoded; the count refers to chars in the original string.
+    assert_eq!(s.as_str(), "?q=a%20%E2%80%A6%20(%2B3%20chars)");
+    let s = compile_args!("?q=", "a b&c" => percent_encode(SET).clip(6, "-"));
+    assert_eq!(s.as_str(), "?q=a%20b-");
+
+    // Post-clip encoding is unaffected.
+    let s = compile_args!("?q=", "a b&c" => clip(2, "…").with_omitted_count().percent_encode(SET));
+    assert_eq!(s.as_str(), "?q=a%20%E2%80%A6%20(%2B3%20chars)");
+}
+
+#[test]
+fn json_objects() {
+    const EMPTY: CompileArgs<2> = compile_json!({});
+    const CONST_OBJECT: CompileArgs<45> =
+        compile_json!({ "id": 42_u16, "name": "\"test\"", 1: 'ß', "neg": -3_i8, });
+    const INNER: CompileArgs<4> = compile_args!("a\tb", 1_u8);
+
+    assert_eq!(EMPTY.as_str(), "{}");
+    assert_eq!(
+        CONST_OBJECT.as_str(),
+        r#"{"id":42,"name":"\"test\"","1":"ß","neg":-3}"#
+    );
+    let object = compile_json!(capacity: 32, { "inner": &INNER, "ascii": Ascii::new("x") });
+    assert_eq!(object.as_str(), r#"{"inner":"a\tb1","ascii":"x"}"#);
+}
+
+#[test]
+fn json_objects_with_dynamic_values() {
+    const fn status_frame(code: u32, msg: &str) -> CompileArgs<82> {
+        compile_json!({ "code": code => fmt::<u32>(), "msg": msg => clip(8, "…") })
+    }
+
+    let frame = status_frame(200, "OK");
+    assert_eq!(frame.as_str(), r#"{"code":200,"msg":"OK"}"#);
+    let frame = status_frame(u32::MAX, "\n\n\n\n\n\n\n\n\n");
+    assert_eq!(
+        frame.as_str(),
+        r#"{"code":4294967295,"msg":"\n\n\n\n\n\n\n\n…"}"#
+    );
+    let frame = status_frame(u32::MAX, "\u{1}\u{1}\u{1}\u{1}\u{1}\u{1}\u{1}\u{1}ß");
+    assert_eq!(
+        frame.as_str(),
+        r#"{"code":4294967295,"msg":"\u0001\u0001\u0001\u0001\u0001\u0001\u0001\u0001…"}"#
+    );
+
+    // Explicitly quoted formats are used as is.
+    let msg = "a\"b\"c";
+    let object = compile_json!({ "msg": msg => str_fmt().json_str().clip(4, "…") });
+    assert_eq!(object.as_str(), r#"{"msg":"a\"b…"}"#);
+}
+
+#[test]
+#[should_panic(expected = "JSON string values can only be quoted with `json_str()`")]
+fn json_values_with_debug_quoting() {
+    let _ = fmt::<char>().debug().json_value();
+}
+
+#[test]
+fn kv_lines() {
+    const LINE: CompileArgs<58> = compile_kv!(
+        level = "info",
+        empty = "",
+        msg = "a=b",
+        ch = ' ',
+        quoted = Ascii::new("\"q\"\n"),
+        n = -5_i32,
+    );
+    assert_eq!(
+        LINE.as_str(),
+        r#"level=info empty="" msg="a=b" ch=" " quoted="\"q\"\n" n=-5"#
+    );
+
+    let line = compile_kv!(capacity: 128, x = 'ß', inner = &LINE);
+    assert_eq!(
+        line.as_str(),
+        r#"x=ß inner="level=info empty=\"\" msg=\"a=b\" ch=\" \" quoted=\"\\\"q\\\"\\n\" n=-5""#
+    );
+}
+
+#[test]
+fn kv_lines_with_dynamic_values() {
+    const fn log_line(code: u16, path: &str) -> CompileArgs<60> {
+        compile_kv!(level = "error", code = code => fmt::<u16>(), path = path => clip(4, "…"))
+    }
+
+    assert_eq!(
+        log_line(42, "a b").as_str(),
+        r#"level=error code=42 path="a b""#
+    );
+    assert_eq!(
+        log_line(42, "a\u{a0}b").as_str(),
+        "level=error code=42 path=\"a\u{a0}b\""
+    );
+    assert_eq!(
+        log_line(42, "/a/b/c").as_str(),
+        "level=error code=42 path=/a/b…"
+    );
+    // Quoting is decided based on the entire string.
+    assert_eq!(
+        log_line(65_535, "/a/b c").as_str(),
+        r#"level=error code=65535 path="/a/b…""#
+    );
+    assert_eq!(
+        log_line(1, "\"\"\"\"\"").as_str(),
+        r#"level=error code=1 path="\"\"\"\"…""#
+    );
+
+    let path = "a b c";
+    let line = compile_kv!(path = path => clip(3, "").with_omitted_count());
+    assert_eq!(line.as_str(), r#"path="a b (+2 chars)""#);
+    // Explicitly quoted formats are used as is.
+    let path = "a\tb";
+    let line = compile_kv!(path = path => str_fmt().debug().clip(3, "…"));
+    assert_eq!(line.as_str(), r#"path="a\t…""#);
+}
+
 #[test]
 fn padding() {
     let num = 42_u64;
@@ -94,6 +665,165 @@ fn padding() {
     assert_eq!(s.as_str(), "number: [420000]");
 }
 
+#[test]
+fn prefix_and_suffix() {
+    let value = 42_u32;
+    let s = compile_args!("[", value => fmt::<u32>().prefix("0x").suffix("h"), "]");
+    assert_eq!(s.as_str(), "[0x42h]");
+    let s = compile_args!("[", value => fmt::<u32>().suffix(" ms").pad_right(7, ' '), "]");
+    assert_eq!(s.as_str(), "[  42 ms]");
+    let s = compile_args!("[", "test" => clip(2, "…").prefix("→ ").pad_left(6, '.'), "]");
+    assert_eq!(s.as_str(), "[→ te….]");
+    let s = ascii_args!("[", -5 => fmt::<i32>().prefix("x=").pad_center(6, ' '), "]");
+    assert_eq!(s.as_str(), "[ x=-5 ]");
+
+    let nested = compile_args!(capacity: 8, "test");
+    let s = compile_args!("[", &nested => fmt::<&CompileArgs<8>>().prefix("<").suffix(">"), "]");
+    assert_eq!(s.as_str(), "[<test>]");
+}
+
+#[test]
+fn conditional_prefix_and_suffix() {
+    const fn format_value(value: i64, unit: &str) -> impl AsRef<str> {
+        compile_args!(
+            value => fmt::<i64>().prefix("v=").skip_affixes_if_empty().pad_left(4, '_'),
+            unit => clip(8, "").prefix(" ").skip_affixes_if_empty()
+        )
+    }
+
+    assert_eq!(format_value(1, "ms").as_ref(), "v=1_ ms");
+    assert_eq!(format_value(0, "ms").as_ref(), "0___ ms");
+    assert_eq!(format_value(-1, "").as_ref(), "v=-1");
+    assert_eq!(format_value(0, "").as_ref(), "0___");
+
+    let n = 0_u32;
+    let s = compile_args!("[", n => fmt::<u32>().prefix("code=").skip_affixes_if_empty(), "]");
+    assert_eq!(s.as_str(), "[0]");
+}
+
+#[test]
+fn center_padding_bias() {
+    const BIAS: CenterBias = CenterBias::ExtraOnLeft;
+
+    for width in 0..8 {
+        let s = compile_args!("[", "ab" => clip(2, "").pad_center_dyn(8, '_'); width, "]");
+        assert_eq!(s.as_str(), std::format!("[{:_^width$}]", "ab"));
+    }
+
+    let s = compile_args!(
+        "[", "ab" => clip(2, "").pad_center_with(5, '_', CenterBias::ExtraOnRight), "]"
+    );
+    assert_eq!(s.as_str(), "[_ab__]");
+    let s = compile_args!(
+        "[", "ab" => clip(2, "").pad_center_with(5, '_', CenterBias::ExtraOnLeft), "]"
+    );
+    assert_eq!(s.as_str(), "[__ab_]");
+    let s = compile_args!(
+        "[", "ab" => clip(2, "").pad_center_with(6, '_', CenterBias::ExtraOnLeft), "]"
+    );
+    assert_eq!(s.as_str(), "[__ab__]");
+
+    let s =
+        compile_args!("[", "ab" => clip(2, "").pad_center_pattern(5, "-=").center_bias(BIAS), "]");
+    assert_eq!(s.as_str(), "[-=ab-]");
+    let s =
+        compile_args!("[", "日" => clip(1, "").pad_center_columns(5, '_').center_bias(BIAS), "]");
+    assert_eq!(s.as_str(), "[__日_]");
+    for (width, expected) in [(1, "[ab]"), (5, "[__ab_]"), (6, "[__ab__]")] {
+        let s = compile_args!(
+            "[", "ab" => clip(2, "").pad_center_dyn(8, '_').center_bias(BIAS); width, "]"
+        );
+        assert_eq!(s.as_str(), expected);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Center bias can only be specified for center-aligned padding")]
+fn center_bias_without_center_padding_panic() {
+    let _ = fmt::<u32>()
+        .pad_left(4, ' ')
+        .center_bias(CenterBias::ExtraOnLeft);
+}
+
+#[test]
+fn padding_with_dynamic_width() {
+    const fn pad_number(value: u32, width: usize) -> CompileArgs<12> {
+        compile_args!("[", value => fmt::<u32>().pad_right_dyn(8, '0'); width, "]")
+    }
+
+    assert_eq!(pad_number(42, 0).as_str(), "[42]");
+    assert_eq!(pad_number(42, 4).as_str(), "[0042]");
+    assert_eq!(pad_number(42, 8).as_str(), "[00000042]");
+    assert_eq!(pad_number(123_456, 4).as_str(), "[123456]");
+
+    let s = compile_args!("[", "test" => clip(4, "").pad_center_dyn(8, '-'); 7, "]");
+    assert_eq!(s.as_str(), "[-test--]");
+    let s = compile_args!("[", "test" => clip(4, "").pad_center_dyn(8, '-'), "]");
+    assert_eq!(s.as_str(), "[--test--]");
+    let s = ascii_args!("[", 42 => fmt::<i32>().pad_left_dyn(8, ' '); 3, "]");
+    assert_eq!(s.as_str(), "[42 ]");
+}
+
+#[test]
+#[should_panic(expected = "Padding width 9 exceeds the maximum width 8")]
+fn padding_with_dynamic_width_panic() {
+    let width = 9;
+    compile_args!("[", 42 => fmt::<i32>().pad_left_dyn(8, ' '); width, "]");
+}
+
+#[test]
+#[should_panic(expected = "Padding width can only be specified for formats with dynamic padding")]
+fn padding_width_for_static_padding_panic() {
+    let _ = ArgumentWrapper::new(42)
+        .with_fmt(fmt::<i32>().pad_left(8, ' '))
+        .into_argument()
+        .with_pad_width(3);
+}
+
+#[test]
+fn padding_with_patterns() {
+    let s = compile_args!("[", "test" => clip(8, "").pad_left_pattern(9, "=-"), "]");
+    assert_eq!(s.as_str(), "[test=-=-=]");
+    let s = compile_args!("[", "test" => clip(8, "").pad_right_pattern(9, "=-"), "]");
+    assert_eq!(s.as_str(), "[=-=-=test]");
+    let s = compile_args!("[", "test" => clip(8, "").pad_center_pattern(9, "=-"), "]");
+    assert_eq!(s.as_str(), "[=-test=-=]");
+    let s = compile_args!("[", 42 => fmt::<i32>().pad_right_pattern(7, "💣ℝ"), "]");
+    assert_eq!(s.as_str(), "[💣ℝ💣ℝ💣42]");
+    let s = compile_args!("[", "test" => clip(8, "").pad_left_pattern(4, "=-"), "]");
+    assert_eq!(s.as_str(), "[test]");
+}
+
+#[test]
+fn padding_ascii_args_with_patterns() {
+    let s = ascii_args!("[", 42 => fmt::<i32>().pad_right_pattern(5, ". "), "]");
+    assert_eq!(s.as_str(), "[. .42]");
+}
+
+#[test]
+fn padding_in_columns() {
+    let s = compile_args!("[", "日本" => clip(4, "").pad_left_columns(6, ' '), "]");
+    assert_eq!(s.as_str(), "[日本  ]");
+    let s = compile_args!("[", "日本" => clip(4, "").pad_center_columns(7, '-'), "]");
+    assert_eq!(s.as_str(), "[-日本--]");
+    let s = compile_args!("[", "Zoe\u{308}" => clip(4, "").pad_right_columns(4, ' '), "]");
+    assert_eq!(s.as_str(), "[ Zoe\u{308}]");
+    let s = compile_args!("[", '💣' => fmt::<char>().pad_right_columns(3, ' '), "]");
+    assert_eq!(s.as_str(), "[ 💣]");
+    let s = compile_args!("[", 42 => fmt::<i32>().pad_right_columns(4, '0'), "]");
+    assert_eq!(s.as_str(), "[0042]");
+
+    // Compare with char-based padding
+    let s = compile_args!("[", "日本" => clip(4, "").pad_left(6, ' '), "]");
+    assert_eq!(s.as_str(), "[日本    ]");
+}
+
+#[test]
+#[should_panic(expected = "Padding char must occupy exactly 1 column")]
+fn padding_in_columns_with_wide_char() {
+    let _ = fmt::<u32>().pad_left_columns(4, '💣');
+}
+
 #[test]
 fn clipping_and_padding() {
     let arg = "test string";
@@ -127,6 +857,24 @@ fn clipping_and_padding() {
     assert_eq!(s.as_str(), "string: [test…]");
 }
 
+#[test]
+fn clipping_and_padding_nested_args() {
+    type ValueArgs = CompileArgs<16>;
+
+    let value: ValueArgs = compile_args!(capacity: 16, "value: ", 42_u32);
+    let s = compile_args!(
+        "[", &value => fmt::<&ValueArgs>().pad_left(12, ' '), "]"
+    );
+    assert_eq!(s.as_str(), "[value: 42   ]");
+
+    let s = compile_args!(
+        "[", &value => fmt::<&ValueArgs>().clip(5, "…").pad_right(8, 'ß'), "]"
+    );
+    assert_eq!(s.as_str(), "[ßßvalue…]");
+    let s = compile_args!("[", &value => fmt::<&ValueArgs>().clip(32, "…"), "]");
+    assert_eq!(s.as_str(), "[value: 42]");
+}
+
 #[test]
 fn ascii_strings() {
     let s: CompileArgs<11> = compile_args!("ASCII: ", Ascii::new("test"));
@@ -144,6 +892,31 @@ fn ascii_strings() {
     assert_eq!(s.as_str(), "ASCII: te~");
 }
 
+#[test]
+fn ascii_args() {
+    type CodeArgs = AsciiArgs<11>;
+
+    const fn format_code(code: i16, name: Ascii<'_>) -> CodeArgs {
+        ascii_args!(name => clip_ascii(4, ""), '=', code => fmt::<i16>())
+    }
+
+    let code = format_code(-42, Ascii::new("test"));
+    assert_eq!(code.as_str(), "test=-42");
+    let s: CompileArgs<12> = compile_args!(
+        "[", &code => fmt::<&CodeArgs>().clip(6, "~").pad_center(10, ' '), "]"
+    );
+    assert_eq!(s.as_str(), "[ test=-~  ]");
+
+    let args = AsciiArgs::new(compile_args!("test"));
+    assert_eq!(args.into_inner(), "test");
+}
+
+#[test]
+#[should_panic(expected = "String 'teß' contains non-ASCII chars; first at position 2")]
+fn ascii_args_panic() {
+    AsciiArgs::new(compile_args!("teß"));
+}
+
 #[test]
 #[should_panic(expected = "expected 1 to be greater than 32")]
 fn assertion() {
@@ -0,0 +1,611 @@
+//! Char-level string transforms and their application.
+
+use core::mem;
+
+use crate::{
+    argument::log_10_ceil,
+    format::StrLength,
+    percent::PercentEncodeSet,
+    unicode::{char_columns, count_columns, is_grapheme_extended, is_printable},
+    utils::{count_chars_in_bytes, decode_char},
+    CompileArgs,
+};
+
+/// Maximum number of char maps in a chain.
+const MAX_MAPS: usize = 4;
+/// Maximum number of chars a single char can be mapped to by a chain of maps.
+const MAX_MAPPED_CHARS: usize = 32;
+
+/// Transform of a string that is applied to each char independently.
+///
+/// Maps must not increase the maximum byte length of chars; in particular, ASCII chars must be mapped
+/// to ASCII chars. This allows to retain capacity estimates based on [`FormatArgument::MAX_BYTES_PER_CHAR`].
+///
+/// [`FormatArgument::MAX_BYTES_PER_CHAR`]: crate::FormatArgument::MAX_BYTES_PER_CHAR
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum CharMap {
+    AsciiUppercase,
+    AsciiLowercase,
+    /// Escaping similar to the `Debug` implementation for `str` and `char`, with the specified quote char
+    /// being escaped.
+    EscapeDebug {
+        quote: char,
+    },
+    /// Escaping for JSON strings as per RFC 8259.
+    EscapeJson,
+    /// URL percent-encoding of the chars in the set.
+    PercentEncode(PercentEncodeSet),
+}
+
+impl CharMap {
+    /// Returns the maximum number of chars a single char is mapped to.
+    const fn max_expansion(self) -> usize {
+        match self {
+            Self::AsciiUppercase | Self::AsciiLowercase => 1,
+            Self::EscapeDebug { .. } => MAX_UNICODE_ESCAPE_LEN,
+            Self::EscapeJson => MAX_JSON_ESCAPE_LEN,
+            Self::PercentEncode(_) => MAX_PERCENT_ENCODING_LEN,
+        }
+    }
+
+    /// Checks whether this map escapes chars (as opposed to, e.g., changing their case). Escaping maps
+    /// applied before clipping also apply to the replacer and the omitted count.
+    const fn is_escaping(self) -> bool {
+        matches!(
+            self,
+            Self::EscapeDebug { .. } | Self::EscapeJson | Self::PercentEncode(_)
+        )
+    }
+
+    /// Transforms an upper boundary for the length of the input string into the boundary for the output.
+    const fn bound(self, len: StrLength) -> StrLength {
+        match self {
+            Self::AsciiUppercase | Self::AsciiLowercase => len,
+            Self::EscapeDebug { .. } => {
+                // Each char is either retained or escaped; an escape is ASCII and is no longer than
+                // 6 bytes for ASCII chars (e.g., `\u{7f}`) and `MAX_UNICODE_ESCAPE_LEN` bytes in general.
+                let bytes = min(
+                    len.bytes.saturating_mul(6),
+                    len.chars.saturating_mul(MAX_UNICODE_ESCAPE_LEN),
+                );
+                let chars = min(len.chars.saturating_mul(MAX_UNICODE_ESCAPE_LEN), bytes);
+                StrLength {
+                    bytes,
+                    chars,
+                    columns: bytes,
+                }
+            }
+            Self::EscapeJson => {
+                // Only ASCII chars are escaped, with the escape no longer than 6 bytes (e.g., `\u001f`).
+                // Retained chars are no longer than 4 bytes, so each char maps to at most 6 bytes.
+                let bytes = min(
+                    len.bytes.saturating_mul(MAX_JSON_ESCAPE_LEN),
+                    len.chars.saturating_mul(MAX_JSON_ESCAPE_LEN),
+                );
+                let chars = min(len.chars.saturating_mul(MAX_JSON_ESCAPE_LEN), bytes);
+                StrLength {
+                    bytes,
+                    chars,
+                    columns: bytes,
+                }
+            }
+            Self::PercentEncode(_) => {
+                // Each byte is encoded as at most 3 ASCII chars (`%XX`).
+                let bytes = min(
+                    len.bytes.saturating_mul(3),
+                    len.chars.saturating_mul(MAX_PERCENT_ENCODING_LEN),
+                );
+                StrLength::both(bytes)
+            }
+        }
+    }
+
+    const fn apply(self, c: char, output: MappedChars) -> MappedChars {
+        match self {
+            Self::AsciiUppercase => output.push(c.to_ascii_uppercase()),
+            Self::AsciiLowercase => output.push(c.to_ascii_lowercase()),
+            Self::EscapeDebug { quote } => match c {
+                '\0' => output.push('\\').push('0'),
+                '\t' => output.push('\\').push('t'),
+                '\r' => output.push('\\').push('r'),
+                '\n' => output.push('\\').push('n'),
+                '\\' => output.push('\\').push('\\'),
+                _ if c == quote => output.push('\\').push(c),
+                _ if is_printable(c as u32) && !is_grapheme_extended(c as u32) => output.push(c),
+                _ => Self::push_unicode_escape(c, output),
+            },
+            Self::EscapeJson => match c {
+                '"' | '\\' => output.push('\\').push(c),
+                '\u{8}' => output.push('\\').push('b'),
+                '\u{c}' => output.push('\\').push('f'),
+                '\n' => output.push('\\').push('n'),
+                '\r' => output.push('\\').push('r'),
+                '\t' => output.push('\\').push('t'),
+                '\0'..='\u{1f}' => {
+                    let code = c as usize;
+                    output
+                        .push('\\')
+                        .push('u')
+                        .push('0')
+                        .push('0')
+                        .push(HEX_DIGITS[code >> 4] as char)
+                        .push(HEX_DIGITS[code & 0xf] as char)
+                }
+                _ => output.push(c),
+            },
+            Self::PercentEncode(set) => {
+                if !set.encodes(c) {
+                    return output.push(c);
+                }
+                let encoded = CompileArgs::<4>::new().write_char(c);
+                let bytes = encoded.as_str().as_bytes();
+                let mut output = output;
+                let mut i = 0;
+                while i < bytes.len() {
+                    let byte = bytes[i] as usize;
+                    output = output
+                        .push('%')
+                        .push((HEX_DIGITS[byte >> 4] as char).to_ascii_uppercase())
+                        .push((HEX_DIGITS[byte & 0xf] as char).to_ascii_uppercase());
+                    i += 1;
+                }
+                output
+            }
+        }
+    }
+
+    /// Pushes an escape sequence like `\u{301}` for the specified char.
+    #[allow(clippy::cast_possible_truncation)] // false positive
+    const fn push_unicode_escape(c: char, mut output: MappedChars) -> MappedChars {
+        let code = c as u32;
+        output = output.push('\\').push('u').push('{');
+        let mut shift = 20;
+        while shift > 0 && code >> shift == 0 {
+            shift -= 4;
+        }
+        loop {
+            output = output.push(HEX_DIGITS[(code >> shift & 0xf) as usize] as char);
+            if shift == 0 {
+                break;
+            }
+            shift -= 4;
+        }
+        output.push('}')
+    }
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+/// Maximum length of an escape sequence for a char (`\u{10ffff}`).
+const MAX_UNICODE_ESCAPE_LEN: usize = 10;
+/// Maximum length of a JSON escape sequence for a char (`\u001f`).
+const MAX_JSON_ESCAPE_LEN: usize = 6;
+/// Maximum length of a percent-encoded char (4 `%XX` triplets).
+const MAX_PERCENT_ENCODING_LEN: usize = 12;
+
+const fn min(x: usize, y: usize) -> usize {
+    if x < y {
+        x
+    } else {
+        y
+    }
+}
+
+/// Chars produced by mapping a single char.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MappedChars {
+    chars: [char; MAX_MAPPED_CHARS],
+    len: usize,
+}
+
+impl MappedChars {
+    const EMPTY: Self = Self {
+        chars: ['\0'; MAX_MAPPED_CHARS],
+        len: 0,
+    };
+
+    const fn push(mut self, c: char) -> Self {
+        self.chars[self.len] = c;
+        self.len += 1;
+        self
+    }
+}
+
+/// Ordered chain of [`CharMap`]s.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CharMaps {
+    maps: [CharMap; MAX_MAPS],
+    len: usize,
+}
+
+impl CharMaps {
+    pub(crate) const EMPTY: Self = Self {
+        maps: [CharMap::AsciiUppercase; MAX_MAPS],
+        len: 0,
+    };
+
+    pub(crate) const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a map to the end of this chain.
+    pub(crate) const fn push(mut self, map: CharMap) -> Self {
+        assert!(
+            self.len < MAX_MAPS,
+            "Too many char transforms in a single format"
+        );
+        self.maps[self.len] = map;
+        self.len += 1;
+
+        let mut expansion = 1;
+        let mut i = 0;
+        while i < self.len {
+            expansion *= self.maps[i].max_expansion();
+            i += 1;
+        }
+        assert!(
+            expansion <= MAX_MAPPED_CHARS,
+            "Char transforms in a single format expand chars too much"
+        );
+        self
+    }
+
+    /// Transforms an upper boundary for the length of the input string into the boundary for the output.
+    pub(crate) const fn bound(&self, mut len: StrLength) -> StrLength {
+        let mut i = 0;
+        while i < self.len {
+            len = self.maps[i].bound(len);
+            i += 1;
+        }
+        len
+    }
+
+    /// Returns escaping maps from this chain, retaining their order.
+    pub(crate) const fn escaping(&self) -> Self {
+        let mut escaping = Self::EMPTY;
+        let mut i = 0;
+        while i < self.len {
+            if self.maps[i].is_escaping() {
+                escaping = escaping.push(self.maps[i]);
+            }
+            i += 1;
+        }
+        escaping
+    }
+
+    const fn map(&self, c: char) -> MappedChars {
+        let mut chars = MappedChars::EMPTY.push(c);
+        let mut i = 0;
+        while i < self.len {
+            let mut mapped = MappedChars::EMPTY;
+            let mut j = 0;
+            while j < chars.len {
+                mapped = self.maps[i].apply(chars.chars[j], mapped);
+                j += 1;
+            }
+            chars = mapped;
+            i += 1;
+        }
+        chars
+    }
+}
+
+/// Decodes a char starting at the byte offset `pos` in a valid UTF-8 byte sequence.
+#[allow(unknown_lints, unnecessary_transmutes)] // `char::from_u32_unchecked()` is not const on MSRV
+const fn char_at(s_bytes: &[u8], pos: usize) -> (char, usize) {
+    let (code, char_len) = decode_char(s_bytes, pos);
+    // SAFETY: decoding valid UTF-8 always produces a valid Unicode scalar value.
+    let c = unsafe { mem::transmute::<u32, char>(code) };
+    (c, char_len)
+}
+
+/// Receiver of transformed strings. Depending on the mode, the sink either writes strings to [`CompileArgs`],
+/// or only measures their length.
+#[derive(Debug)]
+pub(crate) struct StrSink<const CAP: usize> {
+    args: CompileArgs<CAP>,
+    len: StrLength,
+    dry_run: bool,
+    after_zwj: bool,
+}
+
+impl StrSink<0> {
+    /// Creates a sink only measuring the length of received strings.
+    pub(crate) const fn measure() -> Self {
+        Self {
+            args: CompileArgs::new(),
+            len: StrLength::both(0),
+            dry_run: true,
+            after_zwj: false,
+        }
+    }
+}
+
+impl<const CAP: usize> StrSink<CAP> {
+    /// Creates a sink writing to the specified arguments.
+    pub(crate) const fn new(args: CompileArgs<CAP>) -> Self {
+        Self {
+            args,
+            len: StrLength::both(0),
+            dry_run: false,
+            after_zwj: false,
+        }
+    }
+
+    pub(crate) const fn into_args(self) -> CompileArgs<CAP> {
+        self.args
+    }
+
+    /// Returns the total length of strings received by this sink.
+    pub(crate) const fn len(&self) -> StrLength {
+        self.len
+    }
+
+    pub(crate) const fn push_char(mut self, c: char) -> Self {
+        // Chars joined by ZWJ (e.g., in emoji ZWJ sequences) are rendered as a single glyph.
+        let columns = if self.after_zwj {
+            0
+        } else {
+            char_columns(c as u32)
+        };
+        self.after_zwj = c == '\u{200d}';
+        self.len = self.len.sum(StrLength {
+            bytes: c.len_utf8(),
+            chars: 1,
+            columns,
+        });
+        if !self.dry_run {
+            self.args = self.args.write_char(c);
+        }
+        self
+    }
+
+    /// Pushes a char transformed by the specified maps.
+    pub(crate) const fn push_mapped(mut self, c: char, maps: &CharMaps) -> Self {
+        let mapped = maps.map(c);
+        let mut i = 0;
+        while i < mapped.len {
+            self = self.push_char(mapped.chars[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Pushes a char transformed by `pre` maps and then by `post` maps.
+    const fn push_mapped_twice(mut self, c: char, pre: &CharMaps, post: &CharMaps) -> Self {
+        let mapped = pre.map(c);
+        let mut i = 0;
+        while i < mapped.len {
+            self = self.push_mapped(mapped.chars[i], post);
+            i += 1;
+        }
+        self
+    }
+
+    /// Pushes a valid UTF-8 byte sequence transformed by `pre` maps and then by `post` maps.
+    pub(crate) const fn push_bytes_twice(
+        mut self,
+        s_bytes: &[u8],
+        pre: &CharMaps,
+        post: &CharMaps,
+    ) -> Self {
+        if pre.is_empty() {
+            return self.push_bytes(s_bytes, post);
+        }
+        let mut pos = 0;
+        while pos < s_bytes.len() {
+            let (c, char_len) = char_at(s_bytes, pos);
+            self = self.push_mapped_twice(c, pre, post);
+            pos += char_len;
+        }
+        self
+    }
+
+    /// Pushes a valid UTF-8 byte sequence transformed by the specified maps.
+    pub(crate) const fn push_bytes(mut self, s_bytes: &[u8], maps: &CharMaps) -> Self {
+        if maps.is_empty() {
+            self.len = self.len.sum(StrLength {
+                bytes: s_bytes.len(),
+                chars: count_chars_in_bytes(s_bytes),
+                columns: count_columns(s_bytes),
+            });
+            self.after_zwj = false;
+            if !self.dry_run {
+                self.args = self.args.write_str_bytes(s_bytes);
+            }
+            return self;
+        }
+
+        let mut pos = 0;
+        while pos < s_bytes.len() {
+            let (c, char_len) = char_at(s_bytes, pos);
+            self = self.push_mapped(c, maps);
+            pos += char_len;
+        }
+        self
+    }
+
+    /// Pushes decimal digits of an integer transformed by the specified maps.
+    #[allow(clippy::cast_possible_truncation)] // false positive
+    pub(crate) const fn push_usize(mut self, value: usize, maps: &CharMaps) -> Self {
+        let digit_count = log_10_ceil(value as u128);
+        let mut i = digit_count;
+        while i > 0 {
+            i -= 1;
+            let digit = (value / 10_usize.pow(i as u32)) % 10;
+            self = self.push_mapped((b'0' + digit as u8) as char, maps);
+        }
+        self
+    }
+
+    /// Pushes chars of a string transformed by `pre` maps and then by `post` maps. Only chars with indices
+    /// (after applying `pre` maps) lying outside the range `head..tail_start` are pushed; the range
+    /// (if non-empty) is replaced with `using` transformed by escaping `pre` maps and then by `post` maps,
+    /// so that the replacer cannot break escaping. Chars produced by `pre` maps
+    /// from a single char (e.g., an escape sequence) are never split; if they are partially in the range,
+    /// they are replaced as a whole.
+    ///
+    /// Returns the sink and the number of replaced chars in the original string (i.e., before applying `pre` maps).
+    pub(crate) const fn push_clipped(
+        mut self,
+        s: &str,
+        pre: &CharMaps,
+        (head, tail_start): (usize, usize),
+        using: &str,
+        post: &CharMaps,
+    ) -> (Self, usize) {
+        let s_bytes = s.as_bytes();
+        let mut pos = 0;
+        let mut idx = 0;
+        let mut replaced_chars = 0;
+        let mut is_replaced = false;
+        while pos < s_bytes.len() {
+            let (c, char_len) = char_at(s_bytes, pos);
+            let mapped = pre.map(c);
+            let end_idx = idx + mapped.len;
+            if end_idx <= head || idx >= tail_start {
+                let mut i = 0;
+                while i < mapped.len {
+                    self = self.push_mapped(mapped.chars[i], post);
+                    i += 1;
+                }
+            } else {
+                if !is_replaced {
+                    self = self.push_bytes_twice(using.as_bytes(), &pre.escaping(), post);
+                    is_replaced = true;
+                }
+                replaced_chars += 1;
+            }
+            idx = end_idx;
+            pos += char_len;
+        }
+        (self, replaced_chars)
+    }
+}
+
+/// Counts chars in a string transformed by the specified maps.
+pub(crate) const fn count_mapped_chars(s: &str, maps: &CharMaps) -> usize {
+    let s_bytes = s.as_bytes();
+    let mut pos = 0;
+    let mut count = 0;
+    while pos < s_bytes.len() {
+        let (c, char_len) = char_at(s_bytes, pos);
+        count += maps.map(c).len;
+        pos += char_len;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::String;
+
+    use super::*;
+
+    #[test]
+    fn mapping_chars() {
+        let maps = CharMaps::EMPTY.push(CharMap::AsciiUppercase);
+        let mapped = maps.map('a');
+        assert_eq!(mapped.chars[..mapped.len], ['A']);
+        let mapped = maps.map('ß');
+        assert_eq!(mapped.chars[..mapped.len], ['ß']);
+
+        let maps = maps.push(CharMap::AsciiLowercase);
+        let mapped = maps.map('a');
+        assert_eq!(mapped.chars[..mapped.len], ['a']);
+        assert_eq!(count_mapped_chars("Tℝ💣eßt", &maps), 6);
+    }
+
+    #[test]
+    fn escaping_chars() {
+        let maps = CharMaps::EMPTY.push(CharMap::EscapeDebug { quote: '"' });
+        for c in [
+            'a',
+            '\n',
+            '\'',
+            '"',
+            '\\',
+            '\0',
+            'ß',
+            '\u{301}',
+            '\u{7f}',
+            '\u{10ffff}',
+        ] {
+            let mapped = maps.map(c);
+            let mapped: String = mapped.chars[..mapped.len].iter().collect();
+            let expected = std::format!("{:?}", std::string::ToString::to_string(&c));
+            assert_eq!(mapped, expected[1..expected.len() - 1]);
+        }
+    }
+
+    #[test]
+    fn escaping_chars_for_json() {
+        let maps = CharMaps::EMPTY.push(CharMap::EscapeJson);
+        let samples = [
+            ('a', "a"),
+            ('"', "\\\""),
+            ('\\', "\\\\"),
+            ('\n', "\\n"),
+            ('\u{8}', "\\b"),
+            ('\0', "\\u0000"),
+            ('\u{1f}', "\\u001f"),
+            ('\u{7f}', "\u{7f}"),
+            ('/', "/"),
+            ('💣', "💣"),
+        ];
+        for (c, expected) in samples {
+            let mapped = maps.map(c);
+            let mapped: String = mapped.chars[..mapped.len].iter().collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn percent_encoding_chars() {
+        let maps = CharMaps::EMPTY.push(CharMap::PercentEncode(PercentEncodeSet::PATH_SEGMENT));
+        let samples = [
+            ('a', "a"),
+            ('~', "~"),
+            ('@', "@"),
+            (' ', "%20"),
+            ('/', "%2F"),
+            ('%', "%25"),
+            ('\0', "%00"),
+            ('\u{7f}', "%7F"),
+            ('ß', "%C3%9F"),
+            ('ℝ', "%E2%84%9D"),
+            ('💣', "%F0%9F%92%A3"),
+        ];
+        for (c, expected) in samples {
+            let mapped = maps.map(c);
+            let mapped: String = mapped.chars[..mapped.len].iter().collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn measuring_transformed_strings() {
+        let maps = CharMaps::EMPTY.push(CharMap::AsciiUppercase);
+        let sink = StrSink::measure().push_bytes("tℝ💣".as_bytes(), &maps);
+        assert_eq!(
+            sink.len(),
+            StrLength {
+                bytes: 8,
+                chars: 3,
+                columns: 4,
+            }
+        );
+        let sink = sink.push_usize(1_024, &maps);
+        assert_eq!(sink.len().bytes, 12);
+    }
+
+    #[test]
+    fn writing_transformed_strings() {
+        let maps = CharMaps::EMPTY.push(CharMap::AsciiUppercase);
+        let sink = StrSink::new(CompileArgs::<32>::new());
+        let (sink, replaced_chars) =
+            sink.push_clipped("teßt str", &maps, (2, 6), "…", &CharMaps::EMPTY);
+        assert_eq!(replaced_chars, 4);
+        let sink = sink.push_usize(0, &maps).push_usize(507, &maps);
+        assert_eq!(sink.into_args().as_str(), "TE…TR0507");
+    }
+}
@@ -0,0 +1,588 @@
+//! Unicode tables and algorithms used for grapheme cluster segmentation, computing displayed width
+//! and escaping non-printable chars.
+//!
+//! The tables in the [`tables`] module are generated from the Unicode Character Database
+//! (as exposed by the `icu_properties` crate) by the `tables_match_ucd` test; they must not be edited
+//! manually.
+
+use crate::utils::decode_char;
+
+#[rustfmt::skip]
+mod tables;
+
+use self::tables::{GRAPHEME_RANGES, NON_PRINTABLE_RANGES, WIDE_RANGES};
+
+/// Maximum number of chars in a grapheme cluster. Longer clusters (e.g., a letter with a dozen
+/// combining marks) are split.
+pub(crate) const MAX_GRAPHEME_CHARS: usize = 8;
+
+const fn in_ranges(c: u32, ranges: &[(u32, u32)]) -> bool {
+    let mut lo = 0;
+    let mut hi = ranges.len();
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let (start, end) = ranges[mid];
+        if c < start {
+            hi = mid;
+        } else if c > end {
+            lo = mid + 1;
+        } else {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns the number of columns occupied by a char when displayed in a monospace font.
+pub(crate) const fn char_columns(c: u32) -> usize {
+    match GraphemeCategory::new(c) {
+        GraphemeCategory::Cr
+        | GraphemeCategory::Lf
+        | GraphemeCategory::Control
+        | GraphemeCategory::Extend
+        | GraphemeCategory::ExtendLinker
+        | GraphemeCategory::ExtendOther
+        | GraphemeCategory::Zwj
+        | GraphemeCategory::HangulV
+        | GraphemeCategory::HangulT => 0,
+        _ if in_ranges(c, WIDE_RANGES) => 2,
+        _ => 1,
+    }
+}
+
+/// Returns the number of columns occupied by a valid UTF-8 byte sequence when displayed in a monospace font.
+pub(crate) const fn count_columns(s_bytes: &[u8]) -> usize {
+    let mut pos = 0;
+    let mut columns = 0;
+    let mut after_zwj = false;
+    while pos < s_bytes.len() {
+        let (c, char_len) = decode_char(s_bytes, pos);
+        // Chars joined by ZWJ (e.g., in emoji ZWJ sequences) are rendered as a single glyph.
+        if !after_zwj {
+            columns += char_columns(c);
+        }
+        after_zwj = c == 0x200d;
+        pos += char_len;
+    }
+    columns
+}
+
+/// Checks whether a char has the `Grapheme_Extend` property, i.e., is a combining mark or a similar char.
+pub(crate) const fn is_grapheme_extended(c: u32) -> bool {
+    let is_emoji_modifier = matches!(c, 0x1_f3fb..=0x1_f3ff);
+    !is_emoji_modifier
+        && matches!(
+            GraphemeCategory::new(c),
+            GraphemeCategory::Extend
+                | GraphemeCategory::ExtendLinker
+                | GraphemeCategory::ExtendOther
+        )
+}
+
+/// Checks whether a char is printable in the same sense as in the standard library, i.e., is not a control,
+/// format, private use or unassigned char, or a separator other than the ASCII space.
+pub(crate) const fn is_printable(c: u32) -> bool {
+    !in_ranges(c, NON_PRINTABLE_RANGES)
+}
+
+/// Grapheme cluster break category of a char as per [UAX #29], refined with `Indic_Conjunct_Break`
+/// and `Extended_Pictographic` properties.
+///
+/// [UAX #29]: https://www.unicode.org/reports/tr29/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum GraphemeCategory {
+    Cr,
+    Lf,
+    Control,
+    /// `Extend` with `Indic_Conjunct_Break=Extend`.
+    Extend,
+    /// `Extend` with `Indic_Conjunct_Break=Linker` (e.g., viramas).
+    ExtendLinker,
+    /// `Extend` without the `Indic_Conjunct_Break` property (ZWNJ).
+    ExtendOther,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    HangulL,
+    HangulV,
+    HangulT,
+    HangulLv,
+    HangulLvt,
+    /// `Other` with `Indic_Conjunct_Break=Consonant`.
+    Consonant,
+    /// `Other` with the `Extended_Pictographic` property.
+    Pictographic,
+    Other,
+}
+
+impl GraphemeCategory {
+    pub const fn new(c: u32) -> Self {
+        match c {
+            0x0d => Self::Cr,
+            0x0a => Self::Lf,
+            0xac00..=0xd7a3 => {
+                if (c - 0xac00) % 28 == 0 {
+                    Self::HangulLv
+                } else {
+                    Self::HangulLvt
+                }
+            }
+            _ => Self::from_table(c),
+        }
+    }
+
+    const fn from_table(c: u32) -> Self {
+        let mut lo = 0;
+        let mut hi = GRAPHEME_RANGES.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            let (start, end, category) = GRAPHEME_RANGES[mid];
+            if c < start {
+                hi = mid;
+            } else if c > end {
+                lo = mid + 1;
+            } else {
+                return category;
+            }
+        }
+        Self::Other
+    }
+
+    const fn is_extend(self) -> bool {
+        matches!(self, Self::Extend | Self::ExtendLinker | Self::ExtendOther)
+    }
+}
+
+/// State of matching a multi-char sequence affecting grapheme cluster boundaries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SequenceState {
+    None,
+    /// The sequence has started, but cannot be continued by the next cluster yet.
+    Started,
+    /// The sequence can be continued by the next char without a cluster break.
+    Joined,
+}
+
+/// Incremental grapheme cluster segmentation as per [UAX #29] (extended grapheme clusters).
+///
+/// [UAX #29]: https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GraphemeBreaks {
+    prev: GraphemeCategory,
+    /// Number of consecutive regional indicators ending with the previous char.
+    ri_count: usize,
+    /// Matching `\p{Extended_Pictographic} Extend* ZWJ` (rule GB11).
+    emoji: SequenceState,
+    /// Matching `\p{InCB=Consonant} [\p{InCB=Extend}\p{InCB=Linker}]* \p{InCB=Linker} [..]*` (rule `GB9c`).
+    conjunct: SequenceState,
+}
+
+impl GraphemeBreaks {
+    pub const START: Self = Self {
+        prev: GraphemeCategory::Control,
+        ri_count: 0,
+        emoji: SequenceState::None,
+        conjunct: SequenceState::None,
+    };
+
+    /// Processes the next char. Returns the updated state and whether there is a cluster boundary
+    /// before the char (there is always a boundary before the first char).
+    pub const fn push(self, c: u32) -> (Self, bool) {
+        let next = GraphemeCategory::new(c);
+        let is_break = self.is_break_before(next);
+
+        let emoji = match next {
+            GraphemeCategory::Pictographic => SequenceState::Started,
+            _ if next.is_extend() && matches!(self.emoji, SequenceState::Started) => {
+                SequenceState::Started
+            }
+            GraphemeCategory::Zwj if matches!(self.emoji, SequenceState::Started) => {
+                SequenceState::Joined
+            }
+            _ => SequenceState::None,
+        };
+        let conjunct = match (next, self.conjunct) {
+            (GraphemeCategory::Consonant, _) => SequenceState::Started,
+            (_, SequenceState::None) => SequenceState::None,
+            (GraphemeCategory::ExtendLinker, _) => SequenceState::Joined,
+            (GraphemeCategory::Extend | GraphemeCategory::Zwj, state) => state,
+            _ => SequenceState::None,
+        };
+        let ri_count = if let GraphemeCategory::RegionalIndicator = next {
+            self.ri_count + 1
+        } else {
+            0
+        };
+
+        let this = Self {
+            prev: next,
+            ri_count,
+            emoji,
+            conjunct,
+        };
+        (this, is_break)
+    }
+
+    const fn is_break_before(&self, next: GraphemeCategory) -> bool {
+        use GraphemeCategory as G;
+
+        match (self.prev, next) {
+            (G::Cr, G::Lf) => false,
+            (G::Cr | G::Lf | G::Control, _) | (_, G::Cr | G::Lf | G::Control) => true,
+            (G::HangulL, G::HangulL | G::HangulV | G::HangulLv | G::HangulLvt)
+            | (G::HangulLv | G::HangulV, G::HangulV | G::HangulT)
+            | (G::HangulLvt | G::HangulT, G::HangulT)
+            | (_, G::Zwj | G::SpacingMark)
+            | (G::Prepend, _) => false,
+            _ if next.is_extend() => false,
+            (_, G::Consonant) if matches!(self.conjunct, SequenceState::Joined) => false,
+            (G::Zwj, G::Pictographic) if matches!(self.emoji, SequenceState::Joined) => false,
+            (G::RegionalIndicator, G::RegionalIndicator) => self.ri_count % 2 == 0,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fmt::Write as _, format, fs, string::String, string::ToString, vec, vec::Vec};
+
+    use icu_properties::{
+        props::{
+            EastAsianWidth, EmojiModifier, ExtendedPictographic, GeneralCategory,
+            GraphemeClusterBreak, GraphemeExtend, IndicConjunctBreak,
+        },
+        CodePointMapData, CodePointSetData,
+    };
+
+    use super::*;
+    use crate::utils::ClippedStr;
+
+    const MAX_CHAR: u32 = 0x10_ffff;
+
+    fn collect_ranges<T: Copy + PartialEq>(
+        mut value_fn: impl FnMut(u32) -> Option<T>,
+    ) -> Vec<(u32, u32, T)> {
+        let mut ranges: Vec<(u32, u32, T)> = vec![];
+        for c in 0..=MAX_CHAR {
+            let Some(value) = value_fn(c) else {
+                continue;
+            };
+            match ranges.last_mut() {
+                Some((_, end, last_value)) if *end + 1 == c && *last_value == value => *end = c,
+                _ => ranges.push((c, c, value)),
+            }
+        }
+        ranges
+    }
+
+    fn grapheme_category_from_ucd(c: u32) -> Option<GraphemeCategory> {
+        let gcb = CodePointMapData::<GraphemeClusterBreak>::new().get32(c);
+        let incb = CodePointMapData::<IndicConjunctBreak>::new().get32(c);
+        let is_pictographic = CodePointSetData::new::<ExtendedPictographic>().contains32(c);
+
+        Some(match gcb {
+            GraphemeClusterBreak::CR | GraphemeClusterBreak::LF => return None, // handled in code
+            GraphemeClusterBreak::LV | GraphemeClusterBreak::LVT => {
+                assert!((0xac00..=0xd7a3).contains(&c), "{c:x}");
+                return None; // handled in code
+            }
+            GraphemeClusterBreak::Control => GraphemeCategory::Control,
+            GraphemeClusterBreak::Extend => match incb {
+                IndicConjunctBreak::Extend => GraphemeCategory::Extend,
+                IndicConjunctBreak::Linker => GraphemeCategory::ExtendLinker,
+                IndicConjunctBreak::None => GraphemeCategory::ExtendOther,
+                _ => panic!("unexpected InCB for U+{c:04X}: {incb:?}"),
+            },
+            GraphemeClusterBreak::ZWJ => GraphemeCategory::Zwj,
+            GraphemeClusterBreak::RegionalIndicator => GraphemeCategory::RegionalIndicator,
+            GraphemeClusterBreak::Prepend => GraphemeCategory::Prepend,
+            GraphemeClusterBreak::SpacingMark => GraphemeCategory::SpacingMark,
+            GraphemeClusterBreak::L => GraphemeCategory::HangulL,
+            GraphemeClusterBreak::V => GraphemeCategory::HangulV,
+            GraphemeClusterBreak::T => GraphemeCategory::HangulT,
+            GraphemeClusterBreak::Other => match (incb, is_pictographic) {
+                (IndicConjunctBreak::Consonant, false) => GraphemeCategory::Consonant,
+                (IndicConjunctBreak::None, true) => GraphemeCategory::Pictographic,
+                (IndicConjunctBreak::None, false) => return None,
+                _ => panic!("unexpected InCB / ExtPict for U+{c:04X}"),
+            },
+            _ => panic!("unexpected grapheme cluster break for U+{c:04X}: {gcb:?}"),
+        })
+    }
+
+    fn is_printable_from_ucd(c: u32) -> bool {
+        let category = CodePointMapData::<GeneralCategory>::new().get32(c);
+        c == u32::from(' ')
+            || !matches!(
+                category,
+                GeneralCategory::Control
+                    | GeneralCategory::Format
+                    | GeneralCategory::Surrogate
+                    | GeneralCategory::PrivateUse
+                    | GeneralCategory::Unassigned
+                    | GeneralCategory::LineSeparator
+                    | GeneralCategory::ParagraphSeparator
+                    | GeneralCategory::SpaceSeparator
+            )
+    }
+
+    fn is_wide_from_ucd(c: u32) -> bool {
+        let width = CodePointMapData::<EastAsianWidth>::new().get32(c);
+        matches!(width, EastAsianWidth::Wide | EastAsianWidth::Fullwidth)
+    }
+
+    fn write_table(out: &mut String, doc: &str, decl: &str, entries: impl Iterator<Item = String>) {
+        const MAX_LINE_LEN: usize = 100;
+
+        writeln!(out, "{doc}").unwrap();
+        writeln!(out, "{decl} = &[").unwrap();
+        let mut line = String::new();
+        for entry in entries {
+            if !line.is_empty() && line.len() + 1 + entry.len() > MAX_LINE_LEN {
+                writeln!(out, "{line}").unwrap();
+                line.clear();
+            }
+            line.push_str(if line.is_empty() { "    " } else { " " });
+            line.push_str(&entry);
+        }
+        if !line.is_empty() {
+            writeln!(out, "{line}").unwrap();
+        }
+        writeln!(out, "];").unwrap();
+    }
+
+    fn generate_tables() -> String {
+        let mut out = String::from(
+            "//! Unicode tables generated by the `unicode::tests::tables_match_ucd` test. Do not edit manually.\n\
+             //! Ranges in all tables are inclusive and sorted.\n\n\
+             #![allow(clippy::unreadable_literal)]\n\n\
+             use super::GraphemeCategory as G;\n\n",
+        );
+
+        let grapheme_ranges = collect_ranges(grapheme_category_from_ucd);
+        write_table(
+            &mut out,
+            "/// Grapheme categories of chars. Chars not covered by the table have the `Other` category,\n\
+             /// except for CR, LF and precomposed Hangul syllables, which are handled in code.",
+            "pub(super) const GRAPHEME_RANGES: &[(u32, u32, G)]",
+            grapheme_ranges
+                .iter()
+                .map(|(start, end, category)| format!("(0x{start:x}, 0x{end:x}, G::{category:?}),")),
+        );
+
+        let non_printable = collect_ranges(|c| (!is_printable_from_ucd(c)).then_some(()));
+        out.push('\n');
+        write_table(
+            &mut out,
+            "/// Non-printable chars: control, format, private use, surrogate and unassigned chars,\n\
+             /// and separators other than the ASCII space.",
+            "pub(super) const NON_PRINTABLE_RANGES: &[(u32, u32)]",
+            non_printable
+                .iter()
+                .map(|(start, end, ())| format!("(0x{start:x}, 0x{end:x}),")),
+        );
+
+        let wide = collect_ranges(|c| is_wide_from_ucd(c).then_some(()));
+        out.push('\n');
+        write_table(
+            &mut out,
+            "/// Chars occupying 2 columns when displayed (`East_Asian_Width` is `W` or `F`).",
+            "pub(super) const WIDE_RANGES: &[(u32, u32)]",
+            wide.iter()
+                .map(|(start, end, ())| format!("(0x{start:x}, 0x{end:x}),")),
+        );
+        out
+    }
+
+    /// Checks that the tables are up to date. Run with `COMPILE_FMT_UPDATE_TABLES=1` to regenerate them.
+    #[test]
+    fn tables_match_ucd() {
+        const TABLES_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/unicode/tables.rs");
+
+        let expected = generate_tables();
+        if env::var_os("COMPILE_FMT_UPDATE_TABLES").is_some() {
+            fs::write(TABLES_PATH, &expected).unwrap();
+        }
+        let actual = fs::read_to_string(TABLES_PATH).unwrap();
+        assert!(
+            actual == expected,
+            "Unicode tables are outdated; run tests with `COMPILE_FMT_UPDATE_TABLES=1` to update"
+        );
+    }
+
+    #[test]
+    fn properties_match_ucd() {
+        let grapheme_extend = CodePointSetData::new::<GraphemeExtend>();
+        let emoji_modifier = CodePointSetData::new::<EmojiModifier>();
+        for c in 0..=MAX_CHAR {
+            assert_eq!(
+                is_grapheme_extended(c),
+                grapheme_extend.contains32(c),
+                "U+{c:04X}"
+            );
+            if emoji_modifier.contains32(c) {
+                assert!((0x1_f3fb..=0x1_f3ff).contains(&c), "U+{c:04X}");
+            }
+            assert_eq!(is_printable(c), is_printable_from_ucd(c), "U+{c:04X}");
+        }
+    }
+
+    #[test]
+    fn ranges_are_sorted() {
+        for ranges in [NON_PRINTABLE_RANGES, WIDE_RANGES] {
+            for window in ranges.windows(2) {
+                let [(start, end), (next_start, _)] = window else {
+                    unreachable!();
+                };
+                assert!(start <= end && end < next_start, "{window:x?}");
+            }
+        }
+        for window in GRAPHEME_RANGES.windows(2) {
+            let [(start, end, _), (next_start, ..)] = window else {
+                unreachable!();
+            };
+            assert!(start <= end && end < next_start, "{window:x?}");
+        }
+    }
+
+    #[test]
+    fn counting_columns() {
+        assert_eq!(count_columns(b"test"), 4);
+        assert_eq!(count_columns("Tℝ💣eßt".as_bytes()), 7);
+        assert_eq!(count_columns("e\u{301}".as_bytes()), 1);
+        assert_eq!(count_columns("日本語".as_bytes()), 6);
+        assert_eq!(count_columns("👩🏽‍🚒".as_bytes()), 2);
+        assert_eq!(count_columns("한\u{1100}\u{1161}".as_bytes()), 4);
+        assert_eq!(count_columns("ｔｅｓｔ".as_bytes()), 8);
+        assert_eq!(count_columns("a\u{200b}b".as_bytes()), 2);
+        // Spacing marks (e.g., Devanagari vowel sign AA) occupy a column, unlike non-spacing ones.
+        assert_eq!(count_columns("का".as_bytes()), 2);
+        assert_eq!(count_columns("कि".as_bytes()), 2);
+        assert_eq!(count_columns("कु".as_bytes()), 1);
+    }
+
+    #[test]
+    fn getting_grapheme_category() {
+        assert_eq!(GraphemeCategory::new('a'.into()), GraphemeCategory::Other);
+        assert_eq!(GraphemeCategory::new('\n'.into()), GraphemeCategory::Lf);
+        assert_eq!(
+            GraphemeCategory::new('\t'.into()),
+            GraphemeCategory::Control
+        );
+        assert_eq!(GraphemeCategory::new(0x301), GraphemeCategory::Extend);
+        assert_eq!(GraphemeCategory::new(0x200d), GraphemeCategory::Zwj);
+        assert_eq!(GraphemeCategory::new(0xfe0f), GraphemeCategory::Extend);
+        assert_eq!(
+            GraphemeCategory::new('한'.into()),
+            GraphemeCategory::HangulLvt
+        );
+        assert_eq!(
+            GraphemeCategory::new('하'.into()),
+            GraphemeCategory::HangulLv
+        );
+        assert_eq!(
+            GraphemeCategory::new('🇺'.into()),
+            GraphemeCategory::RegionalIndicator
+        );
+        assert_eq!(GraphemeCategory::new(0x94d), GraphemeCategory::ExtendLinker);
+        assert_eq!(GraphemeCategory::new(0x915), GraphemeCategory::Consonant);
+        assert_eq!(GraphemeCategory::new(0xbbe), GraphemeCategory::Extend);
+        assert_eq!(GraphemeCategory::new(0xbbf), GraphemeCategory::SpacingMark);
+        assert_eq!(GraphemeCategory::new(0x600), GraphemeCategory::Prepend);
+        assert_eq!(
+            GraphemeCategory::new('💣'.into()),
+            GraphemeCategory::Pictographic
+        );
+    }
+
+    fn split_graphemes(mut s: &str) -> Vec<&str> {
+        let mut clusters = vec![];
+        while !s.is_empty() {
+            let cluster = match ClippedStr::with_graphemes(s, 1) {
+                ClippedStr::Full(bytes) | ClippedStr::Clipped(bytes) => bytes.len(),
+            };
+            clusters.push(&s[..cluster]);
+            s = &s[cluster..];
+        }
+        clusters
+    }
+
+    #[test]
+    fn segmenting_graphemes() {
+        let samples: &[&[&str]] = &[
+            // Devanagari conjunct + spacing vowel sign
+            &["क्षि", "त"],
+            // Tamil: consonant + virama, consonant + spacing vowel sign
+            &["த்", "மி", "ழ்"],
+            // Telugu, Malayalam conjuncts
+            &["క్ష", "ణ"],
+            &["ക്ഷ", "ണം"],
+            // Kannada virama is not a conjunct linker, so it only extends the preceding consonant
+            &["ಕ್", "ಷ", "ಣ"],
+            // Gujarati / Gurmukhi: virama, nukta
+            &["ક્ષ", "ણ"],
+            &["ਗ਼ੁ", "ਰ"],
+            // Myanmar: consonant + medial, consonant + asat, consonant + vowel sign
+            &["မြ", "န်", "မီ"],
+            // Khmer: consonant + coeng + consonant + vowel sign
+            &["ខ្មែ", "រ"],
+            // Thai SARA AM is a spacing mark
+            &["กำ", "ลั"],
+            // Prepended concatenation mark
+            &["\u{600}1", "2"],
+            // ZWJ only joins pictographic chars
+            &["👩\u{200d}🚒", "a\u{200d}", "b"],
+            &["👍🏽", "🇺🇸", "🇩🇪"],
+            &["\r\n", "\n"],
+        ];
+
+        for &sample in samples {
+            let s: String = sample.concat();
+            let clusters = split_graphemes(&s);
+            assert_eq!(clusters, sample, "{s:?}");
+        }
+    }
+
+    #[test]
+    fn checking_printable_chars() {
+        for c in ['a', ' ', 'ß', '日', '💣', '\u{301}', '\u{1f3fd}'] {
+            assert!(is_printable(c as u32), "{c:?}");
+        }
+        for c in [
+            '\0', '\n', '\u{7f}', '\u{a0}', '\u{200b}', '\u{200d}', '\u{feff}', '\u{e000}',
+            '\u{378}',
+        ] {
+            assert!(!is_printable(c as u32), "{c:?}");
+        }
+
+        assert!(is_grapheme_extended(0x301));
+        assert!(is_grapheme_extended(0x94d));
+        assert!(is_grapheme_extended(0xbcd)); // Tamil virama
+        assert!(is_grapheme_extended(0xacd)); // Gujarati virama
+        assert!(is_grapheme_extended(0xa3c)); // Gurmukhi nukta
+        assert!(!is_grapheme_extended(0x93e));
+        assert!(!is_grapheme_extended(0x1_f3fd));
+        assert!(!is_grapheme_extended('a' as u32));
+    }
+
+    #[test]
+    fn escaping_matches_std() {
+        // Scripts mentioned in the docs + unassigned chars in the Greek block.
+        let blocks = [
+            0x0370..=0x03ff,
+            0x0900..=0x0dff,
+            0x0e00..=0x0eff,
+            0x1000..=0x109f,
+            0x1780..=0x17ff,
+            0x1f300..=0x1faff,
+        ];
+        for c in blocks.into_iter().flatten() {
+            let c = char::from_u32(c).unwrap();
+            let std_escaped = c.to_string().escape_debug().count() > 1;
+            let escaped = !is_printable(c as u32) || is_grapheme_extended(c as u32);
+            assert_eq!(escaped, std_escaped, "{c:?}");
+        }
+    }
+}
@@ -0,0 +1,547 @@
+//! Unicode tables generated by the `unicode::tests::tables_match_ucd` test. Do not edit manually.
+//! Ranges in all tables are inclusive and sorted.
+
+#![allow(clippy::unreadable_literal)]
+
+use super::GraphemeCategory as G;
+
+/// Grapheme categories of chars. Chars not covered by the table have the `Other` category,
+/// except for CR, LF and precomposed Hangul syllables, which are handled in code.
+pub(super) const GRAPHEME_RANGES: &[(u32, u32, G)] = &[
+    (0x0, 0x9, G::Control), (0xb, 0xc, G::Control), (0xe, 0x1f, G::Control),
+    (0x7f, 0x9f, G::Control), (0xa9, 0xa9, G::Pictographic), (0xad, 0xad, G::Control),
+    (0xae, 0xae, G::Pictographic), (0x300, 0x36f, G::Extend), (0x483, 0x489, G::Extend),
+    (0x591, 0x5bd, G::Extend), (0x5bf, 0x5bf, G::Extend), (0x5c1, 0x5c2, G::Extend),
+    (0x5c4, 0x5c5, G::Extend), (0x5c7, 0x5c7, G::Extend), (0x600, 0x605, G::Prepend),
+    (0x610, 0x61a, G::Extend), (0x61c, 0x61c, G::Control), (0x64b, 0x65f, G::Extend),
+    (0x670, 0x670, G::Extend), (0x6d6, 0x6dc, G::Extend), (0x6dd, 0x6dd, G::Prepend),
+    (0x6df, 0x6e4, G::Extend), (0x6e7, 0x6e8, G::Extend), (0x6ea, 0x6ed, G::Extend),
+    (0x70f, 0x70f, G::Prepend), (0x711, 0x711, G::Extend), (0x730, 0x74a, G::Extend),
+    (0x7a6, 0x7b0, G::Extend), (0x7eb, 0x7f3, G::Extend), (0x7fd, 0x7fd, G::Extend),
+    (0x816, 0x819, G::Extend), (0x81b, 0x823, G::Extend), (0x825, 0x827, G::Extend),
+    (0x829, 0x82d, G::Extend), (0x859, 0x85b, G::Extend), (0x890, 0x891, G::Prepend),
+    (0x897, 0x89f, G::Extend), (0x8ca, 0x8e1, G::Extend), (0x8e2, 0x8e2, G::Prepend),
+    (0x8e3, 0x902, G::Extend), (0x903, 0x903, G::SpacingMark), (0x915, 0x939, G::Consonant),
+    (0x93a, 0x93a, G::Extend), (0x93b, 0x93b, G::SpacingMark), (0x93c, 0x93c, G::Extend),
+    (0x93e, 0x940, G::SpacingMark), (0x941, 0x948, G::Extend), (0x949, 0x94c, G::SpacingMark),
+    (0x94d, 0x94d, G::ExtendLinker), (0x94e, 0x94f, G::SpacingMark), (0x951, 0x957, G::Extend),
+    (0x958, 0x95f, G::Consonant), (0x962, 0x963, G::Extend), (0x978, 0x97f, G::Consonant),
+    (0x981, 0x981, G::Extend), (0x982, 0x983, G::SpacingMark), (0x995, 0x9a8, G::Consonant),
+    (0x9aa, 0x9b0, G::Consonant), (0x9b2, 0x9b2, G::Consonant), (0x9b6, 0x9b9, G::Consonant),
+    (0x9bc, 0x9bc, G::Extend), (0x9be, 0x9be, G::Extend), (0x9bf, 0x9c0, G::SpacingMark),
+    (0x9c1, 0x9c4, G::Extend), (0x9c7, 0x9c8, G::SpacingMark), (0x9cb, 0x9cc, G::SpacingMark),
+    (0x9cd, 0x9cd, G::ExtendLinker), (0x9d7, 0x9d7, G::Extend), (0x9dc, 0x9dd, G::Consonant),
+    (0x9df, 0x9df, G::Consonant), (0x9e2, 0x9e3, G::Extend), (0x9f0, 0x9f1, G::Consonant),
+    (0x9fe, 0x9fe, G::Extend), (0xa01, 0xa02, G::Extend), (0xa03, 0xa03, G::SpacingMark),
+    (0xa3c, 0xa3c, G::Extend), (0xa3e, 0xa40, G::SpacingMark), (0xa41, 0xa42, G::Extend),
+    (0xa47, 0xa48, G::Extend), (0xa4b, 0xa4d, G::Extend), (0xa51, 0xa51, G::Extend),
+    (0xa70, 0xa71, G::Extend), (0xa75, 0xa75, G::Extend), (0xa81, 0xa82, G::Extend),
+    (0xa83, 0xa83, G::SpacingMark), (0xa95, 0xaa8, G::Consonant), (0xaaa, 0xab0, G::Consonant),
+    (0xab2, 0xab3, G::Consonant), (0xab5, 0xab9, G::Consonant), (0xabc, 0xabc, G::Extend),
+    (0xabe, 0xac0, G::SpacingMark), (0xac1, 0xac5, G::Extend), (0xac7, 0xac8, G::Extend),
+    (0xac9, 0xac9, G::SpacingMark), (0xacb, 0xacc, G::SpacingMark), (0xacd, 0xacd, G::ExtendLinker),
+    (0xae2, 0xae3, G::Extend), (0xaf9, 0xaf9, G::Consonant), (0xafa, 0xaff, G::Extend),
+    (0xb01, 0xb01, G::Extend), (0xb02, 0xb03, G::SpacingMark), (0xb15, 0xb28, G::Consonant),
+    (0xb2a, 0xb30, G::Consonant), (0xb32, 0xb33, G::Consonant), (0xb35, 0xb39, G::Consonant),
+    (0xb3c, 0xb3c, G::Extend), (0xb3e, 0xb3f, G::Extend), (0xb40, 0xb40, G::SpacingMark),
+    (0xb41, 0xb44, G::Extend), (0xb47, 0xb48, G::SpacingMark), (0xb4b, 0xb4c, G::SpacingMark),
+    (0xb4d, 0xb4d, G::ExtendLinker), (0xb55, 0xb57, G::Extend), (0xb5c, 0xb5d, G::Consonant),
+    (0xb5f, 0xb5f, G::Consonant), (0xb62, 0xb63, G::Extend), (0xb71, 0xb71, G::Consonant),
+    (0xb82, 0xb82, G::Extend), (0xbbe, 0xbbe, G::Extend), (0xbbf, 0xbbf, G::SpacingMark),
+    (0xbc0, 0xbc0, G::Extend), (0xbc1, 0xbc2, G::SpacingMark), (0xbc6, 0xbc8, G::SpacingMark),
+    (0xbca, 0xbcc, G::SpacingMark), (0xbcd, 0xbcd, G::Extend), (0xbd7, 0xbd7, G::Extend),
+    (0xc00, 0xc00, G::Extend), (0xc01, 0xc03, G::SpacingMark), (0xc04, 0xc04, G::Extend),
+    (0xc15, 0xc28, G::Consonant), (0xc2a, 0xc39, G::Consonant), (0xc3c, 0xc3c, G::Extend),
+    (0xc3e, 0xc40, G::Extend), (0xc41, 0xc44, G::SpacingMark), (0xc46, 0xc48, G::Extend),
+    (0xc4a, 0xc4c, G::Extend), (0xc4d, 0xc4d, G::ExtendLinker), (0xc55, 0xc56, G::Extend),
+    (0xc58, 0xc5a, G::Consonant), (0xc62, 0xc63, G::Extend), (0xc81, 0xc81, G::Extend),
+    (0xc82, 0xc83, G::SpacingMark), (0xcbc, 0xcbc, G::Extend), (0xcbe, 0xcbe, G::SpacingMark),
+    (0xcbf, 0xcc0, G::Extend), (0xcc1, 0xcc1, G::SpacingMark), (0xcc2, 0xcc2, G::Extend),
+    (0xcc3, 0xcc4, G::SpacingMark), (0xcc6, 0xcc8, G::Extend), (0xcca, 0xccd, G::Extend),
+    (0xcd5, 0xcd6, G::Extend), (0xce2, 0xce3, G::Extend), (0xcf3, 0xcf3, G::SpacingMark),
+    (0xd00, 0xd01, G::Extend), (0xd02, 0xd03, G::SpacingMark), (0xd15, 0xd3a, G::Consonant),
+    (0xd3b, 0xd3c, G::Extend), (0xd3e, 0xd3e, G::Extend), (0xd3f, 0xd40, G::SpacingMark),
+    (0xd41, 0xd44, G::Extend), (0xd46, 0xd48, G::SpacingMark), (0xd4a, 0xd4c, G::SpacingMark),
+    (0xd4d, 0xd4d, G::ExtendLinker), (0xd4e, 0xd4e, G::Prepend), (0xd57, 0xd57, G::Extend),
+    (0xd62, 0xd63, G::Extend), (0xd81, 0xd81, G::Extend), (0xd82, 0xd83, G::SpacingMark),
+    (0xdca, 0xdca, G::Extend), (0xdcf, 0xdcf, G::Extend), (0xdd0, 0xdd1, G::SpacingMark),
+    (0xdd2, 0xdd4, G::Extend), (0xdd6, 0xdd6, G::Extend), (0xdd8, 0xdde, G::SpacingMark),
+    (0xddf, 0xddf, G::Extend), (0xdf2, 0xdf3, G::SpacingMark), (0xe31, 0xe31, G::Extend),
+    (0xe33, 0xe33, G::SpacingMark), (0xe34, 0xe3a, G::Extend), (0xe47, 0xe4e, G::Extend),
+    (0xeb1, 0xeb1, G::Extend), (0xeb3, 0xeb3, G::SpacingMark), (0xeb4, 0xebc, G::Extend),
+    (0xec8, 0xece, G::Extend), (0xf18, 0xf19, G::Extend), (0xf35, 0xf35, G::Extend),
+    (0xf37, 0xf37, G::Extend), (0xf39, 0xf39, G::Extend), (0xf3e, 0xf3f, G::SpacingMark),
+    (0xf71, 0xf7e, G::Extend), (0xf7f, 0xf7f, G::SpacingMark), (0xf80, 0xf84, G::Extend),
+    (0xf86, 0xf87, G::Extend), (0xf8d, 0xf97, G::Extend), (0xf99, 0xfbc, G::Extend),
+    (0xfc6, 0xfc6, G::Extend), (0x1000, 0x102a, G::Consonant), (0x102d, 0x1030, G::Extend),
+    (0x1031, 0x1031, G::SpacingMark), (0x1032, 0x1037, G::Extend),
+    (0x1039, 0x1039, G::ExtendLinker), (0x103a, 0x103a, G::Extend),
+    (0x103b, 0x103c, G::SpacingMark), (0x103d, 0x103e, G::Extend), (0x103f, 0x103f, G::Consonant),
+    (0x1050, 0x1055, G::Consonant), (0x1056, 0x1057, G::SpacingMark), (0x1058, 0x1059, G::Extend),
+    (0x105a, 0x105d, G::Consonant), (0x105e, 0x1060, G::Extend), (0x1061, 0x1061, G::Consonant),
+    (0x1065, 0x1066, G::Consonant), (0x106e, 0x1070, G::Consonant), (0x1071, 0x1074, G::Extend),
+    (0x1075, 0x1081, G::Consonant), (0x1082, 0x1082, G::Extend), (0x1084, 0x1084, G::SpacingMark),
+    (0x1085, 0x1086, G::Extend), (0x108d, 0x108d, G::Extend), (0x108e, 0x108e, G::Consonant),
+    (0x109d, 0x109d, G::Extend), (0x1100, 0x115f, G::HangulL), (0x1160, 0x11a7, G::HangulV),
+    (0x11a8, 0x11ff, G::HangulT), (0x135d, 0x135f, G::Extend), (0x1712, 0x1715, G::Extend),
+    (0x1732, 0x1734, G::Extend), (0x1752, 0x1753, G::Extend), (0x1772, 0x1773, G::Extend),
+    (0x1780, 0x17b3, G::Consonant), (0x17b4, 0x17b5, G::Extend), (0x17b6, 0x17b6, G::SpacingMark),
+    (0x17b7, 0x17bd, G::Extend), (0x17be, 0x17c5, G::SpacingMark), (0x17c6, 0x17c6, G::Extend),
+    (0x17c7, 0x17c8, G::SpacingMark), (0x17c9, 0x17d1, G::Extend),
+    (0x17d2, 0x17d2, G::ExtendLinker), (0x17d3, 0x17d3, G::Extend), (0x17dd, 0x17dd, G::Extend),
+    (0x180b, 0x180d, G::Extend), (0x180e, 0x180e, G::Control), (0x180f, 0x180f, G::Extend),
+    (0x1885, 0x1886, G::Extend), (0x18a9, 0x18a9, G::Extend), (0x1920, 0x1922, G::Extend),
+    (0x1923, 0x1926, G::SpacingMark), (0x1927, 0x1928, G::Extend), (0x1929, 0x192b, G::SpacingMark),
+    (0x1930, 0x1931, G::SpacingMark), (0x1932, 0x1932, G::Extend), (0x1933, 0x1938, G::SpacingMark),
+    (0x1939, 0x193b, G::Extend), (0x1a17, 0x1a18, G::Extend), (0x1a19, 0x1a1a, G::SpacingMark),
+    (0x1a1b, 0x1a1b, G::Extend), (0x1a20, 0x1a54, G::Consonant), (0x1a55, 0x1a55, G::SpacingMark),
+    (0x1a56, 0x1a56, G::Extend), (0x1a57, 0x1a57, G::SpacingMark), (0x1a58, 0x1a5e, G::Extend),
+    (0x1a60, 0x1a60, G::ExtendLinker), (0x1a62, 0x1a62, G::Extend), (0x1a65, 0x1a6c, G::Extend),
+    (0x1a6d, 0x1a72, G::SpacingMark), (0x1a73, 0x1a7c, G::Extend), (0x1a7f, 0x1a7f, G::Extend),
+    (0x1ab0, 0x1add, G::Extend), (0x1ae0, 0x1aeb, G::Extend), (0x1b00, 0x1b03, G::Extend),
+    (0x1b04, 0x1b04, G::SpacingMark), (0x1b0b, 0x1b0c, G::Consonant),
+    (0x1b13, 0x1b33, G::Consonant), (0x1b34, 0x1b3d, G::Extend), (0x1b3e, 0x1b41, G::SpacingMark),
+    (0x1b42, 0x1b43, G::Extend), (0x1b44, 0x1b44, G::ExtendLinker), (0x1b45, 0x1b4c, G::Consonant),
+    (0x1b6b, 0x1b73, G::Extend), (0x1b80, 0x1b81, G::Extend), (0x1b82, 0x1b82, G::SpacingMark),
+    (0x1b83, 0x1ba0, G::Consonant), (0x1ba1, 0x1ba1, G::SpacingMark), (0x1ba2, 0x1ba5, G::Extend),
+    (0x1ba6, 0x1ba7, G::SpacingMark), (0x1ba8, 0x1baa, G::Extend),
+    (0x1bab, 0x1bab, G::ExtendLinker), (0x1bac, 0x1bad, G::Extend), (0x1bae, 0x1baf, G::Consonant),
+    (0x1bbb, 0x1bbd, G::Consonant), (0x1be6, 0x1be6, G::Extend), (0x1be7, 0x1be7, G::SpacingMark),
+    (0x1be8, 0x1be9, G::Extend), (0x1bea, 0x1bec, G::SpacingMark), (0x1bed, 0x1bed, G::Extend),
+    (0x1bee, 0x1bee, G::SpacingMark), (0x1bef, 0x1bf3, G::Extend), (0x1c24, 0x1c2b, G::SpacingMark),
+    (0x1c2c, 0x1c33, G::Extend), (0x1c34, 0x1c35, G::SpacingMark), (0x1c36, 0x1c37, G::Extend),
+    (0x1cd0, 0x1cd2, G::Extend), (0x1cd4, 0x1ce0, G::Extend), (0x1ce1, 0x1ce1, G::SpacingMark),
+    (0x1ce2, 0x1ce8, G::Extend), (0x1ced, 0x1ced, G::Extend), (0x1cf4, 0x1cf4, G::Extend),
+    (0x1cf7, 0x1cf7, G::SpacingMark), (0x1cf8, 0x1cf9, G::Extend), (0x1dc0, 0x1dff, G::Extend),
+    (0x200b, 0x200b, G::Control), (0x200c, 0x200c, G::ExtendOther), (0x200d, 0x200d, G::Zwj),
+    (0x200e, 0x200f, G::Control), (0x2028, 0x202e, G::Control), (0x203c, 0x203c, G::Pictographic),
+    (0x2049, 0x2049, G::Pictographic), (0x2060, 0x206f, G::Control), (0x20d0, 0x20f0, G::Extend),
+    (0x2122, 0x2122, G::Pictographic), (0x2139, 0x2139, G::Pictographic),
+    (0x2194, 0x2199, G::Pictographic), (0x21a9, 0x21aa, G::Pictographic),
+    (0x231a, 0x231b, G::Pictographic), (0x2328, 0x2328, G::Pictographic),
+    (0x23cf, 0x23cf, G::Pictographic), (0x23e9, 0x23f3, G::Pictographic),
+    (0x23f8, 0x23fa, G::Pictographic), (0x24c2, 0x24c2, G::Pictographic),
+    (0x25aa, 0x25ab, G::Pictographic), (0x25b6, 0x25b6, G::Pictographic),
+    (0x25c0, 0x25c0, G::Pictographic), (0x25fb, 0x25fe, G::Pictographic),
+    (0x2600, 0x2604, G::Pictographic), (0x260e, 0x260e, G::Pictographic),
+    (0x2611, 0x2611, G::Pictographic), (0x2614, 0x2615, G::Pictographic),
+    (0x2618, 0x2618, G::Pictographic), (0x261d, 0x261d, G::Pictographic),
+    (0x2620, 0x2620, G::Pictographic), (0x2622, 0x2623, G::Pictographic),
+    (0x2626, 0x2626, G::Pictographic), (0x262a, 0x262a, G::Pictographic),
+    (0x262e, 0x262f, G::Pictographic), (0x2638, 0x263a, G::Pictographic),
+    (0x2640, 0x2640, G::Pictographic), (0x2642, 0x2642, G::Pictographic),
+    (0x2648, 0x2653, G::Pictographic), (0x265f, 0x2660, G::Pictographic),
+    (0x2663, 0x2663, G::Pictographic), (0x2665, 0x2666, G::Pictographic),
+    (0x2668, 0x2668, G::Pictographic), (0x267b, 0x267b, G::Pictographic),
+    (0x267e, 0x267f, G::Pictographic), (0x2692, 0x2697, G::Pictographic),
+    (0x2699, 0x2699, G::Pictographic), (0x269b, 0x269c, G::Pictographic),
+    (0x26a0, 0x26a1, G::Pictographic), (0x26a7, 0x26a7, G::Pictographic),
+    (0x26aa, 0x26ab, G::Pictographic), (0x26b0, 0x26b1, G::Pictographic),
+    (0x26bd, 0x26be, G::Pictographic), (0x26c4, 0x26c5, G::Pictographic),
+    (0x26c8, 0x26c8, G::Pictographic), (0x26ce, 0x26cf, G::Pictographic),
+    (0x26d1, 0x26d1, G::Pictographic), (0x26d3, 0x26d4, G::Pictographic),
+    (0x26e9, 0x26ea, G::Pictographic), (0x26f0, 0x26f5, G::Pictographic),
+    (0x26f7, 0x26fa, G::Pictographic), (0x26fd, 0x26fd, G::Pictographic),
+    (0x2702, 0x2702, G::Pictographic), (0x2705, 0x2705, G::Pictographic),
+    (0x2708, 0x270d, G::Pictographic), (0x270f, 0x270f, G::Pictographic),
+    (0x2712, 0x2712, G::Pictographic), (0x2714, 0x2714, G::Pictographic),
+    (0x2716, 0x2716, G::Pictographic), (0x271d, 0x271d, G::Pictographic),
+    (0x2721, 0x2721, G::Pictographic), (0x2728, 0x2728, G::Pictographic),
+    (0x2733, 0x2734, G::Pictographic), (0x2744, 0x2744, G::Pictographic),
+    (0x2747, 0x2747, G::Pictographic), (0x274c, 0x274c, G::Pictographic),
+    (0x274e, 0x274e, G::Pictographic), (0x2753, 0x2755, G::Pictographic),
+    (0x2757, 0x2757, G::Pictographic), (0x2763, 0x2764, G::Pictographic),
+    (0x2795, 0x2797, G::Pictographic), (0x27a1, 0x27a1, G::Pictographic),
+    (0x27b0, 0x27b0, G::Pictographic), (0x27bf, 0x27bf, G::Pictographic),
+    (0x2934, 0x2935, G::Pictographic), (0x2b05, 0x2b07, G::Pictographic),
+    (0x2b1b, 0x2b1c, G::Pictographic), (0x2b50, 0x2b50, G::Pictographic),
+    (0x2b55, 0x2b55, G::Pictographic), (0x2cef, 0x2cf1, G::Extend), (0x2d7f, 0x2d7f, G::Extend),
+    (0x2de0, 0x2dff, G::Extend), (0x302a, 0x302f, G::Extend), (0x3030, 0x3030, G::Pictographic),
+    (0x303d, 0x303d, G::Pictographic), (0x3099, 0x309a, G::Extend),
+    (0x3297, 0x3297, G::Pictographic), (0x3299, 0x3299, G::Pictographic),
+    (0xa66f, 0xa672, G::Extend), (0xa674, 0xa67d, G::Extend), (0xa69e, 0xa69f, G::Extend),
+    (0xa6f0, 0xa6f1, G::Extend), (0xa802, 0xa802, G::Extend), (0xa806, 0xa806, G::Extend),
+    (0xa80b, 0xa80b, G::Extend), (0xa823, 0xa824, G::SpacingMark), (0xa825, 0xa826, G::Extend),
+    (0xa827, 0xa827, G::SpacingMark), (0xa82c, 0xa82c, G::Extend), (0xa880, 0xa881, G::SpacingMark),
+    (0xa8b4, 0xa8c3, G::SpacingMark), (0xa8c4, 0xa8c5, G::Extend), (0xa8e0, 0xa8f1, G::Extend),
+    (0xa8ff, 0xa8ff, G::Extend), (0xa926, 0xa92d, G::Extend), (0xa947, 0xa951, G::Extend),
+    (0xa952, 0xa952, G::SpacingMark), (0xa953, 0xa953, G::Extend), (0xa960, 0xa97c, G::HangulL),
+    (0xa980, 0xa982, G::Extend), (0xa983, 0xa983, G::SpacingMark), (0xa989, 0xa98b, G::Consonant),
+    (0xa98f, 0xa9b2, G::Consonant), (0xa9b3, 0xa9b3, G::Extend), (0xa9b4, 0xa9b5, G::SpacingMark),
+    (0xa9b6, 0xa9b9, G::Extend), (0xa9ba, 0xa9bb, G::SpacingMark), (0xa9bc, 0xa9bd, G::Extend),
+    (0xa9be, 0xa9bf, G::SpacingMark), (0xa9c0, 0xa9c0, G::ExtendLinker),
+    (0xa9e0, 0xa9e4, G::Consonant), (0xa9e5, 0xa9e5, G::Extend), (0xa9e7, 0xa9ef, G::Consonant),
+    (0xa9fa, 0xa9fe, G::Consonant), (0xaa29, 0xaa2e, G::Extend), (0xaa2f, 0xaa30, G::SpacingMark),
+    (0xaa31, 0xaa32, G::Extend), (0xaa33, 0xaa34, G::SpacingMark), (0xaa35, 0xaa36, G::Extend),
+    (0xaa43, 0xaa43, G::Extend), (0xaa4c, 0xaa4c, G::Extend), (0xaa4d, 0xaa4d, G::SpacingMark),
+    (0xaa60, 0xaa6f, G::Consonant), (0xaa71, 0xaa73, G::Consonant), (0xaa7a, 0xaa7a, G::Consonant),
+    (0xaa7c, 0xaa7c, G::Extend), (0xaa7e, 0xaa7f, G::Consonant), (0xaab0, 0xaab0, G::Extend),
+    (0xaab2, 0xaab4, G::Extend), (0xaab7, 0xaab8, G::Extend), (0xaabe, 0xaabf, G::Extend),
+    (0xaac1, 0xaac1, G::Extend), (0xaae0, 0xaaea, G::Consonant), (0xaaeb, 0xaaeb, G::SpacingMark),
+    (0xaaec, 0xaaed, G::Extend), (0xaaee, 0xaaef, G::SpacingMark), (0xaaf5, 0xaaf5, G::SpacingMark),
+    (0xaaf6, 0xaaf6, G::ExtendLinker), (0xabc0, 0xabda, G::Consonant),
+    (0xabe3, 0xabe4, G::SpacingMark), (0xabe5, 0xabe5, G::Extend), (0xabe6, 0xabe7, G::SpacingMark),
+    (0xabe8, 0xabe8, G::Extend), (0xabe9, 0xabea, G::SpacingMark), (0xabec, 0xabec, G::SpacingMark),
+    (0xabed, 0xabed, G::Extend), (0xd7b0, 0xd7c6, G::HangulV), (0xd7cb, 0xd7fb, G::HangulT),
+    (0xfb1e, 0xfb1e, G::Extend), (0xfe00, 0xfe0f, G::Extend), (0xfe20, 0xfe2f, G::Extend),
+    (0xfeff, 0xfeff, G::Control), (0xff9e, 0xff9f, G::Extend), (0xfff0, 0xfffb, G::Control),
+    (0x101fd, 0x101fd, G::Extend), (0x102e0, 0x102e0, G::Extend), (0x10376, 0x1037a, G::Extend),
+    (0x10a00, 0x10a00, G::Consonant), (0x10a01, 0x10a03, G::Extend), (0x10a05, 0x10a06, G::Extend),
+    (0x10a0c, 0x10a0f, G::Extend), (0x10a10, 0x10a13, G::Consonant),
+    (0x10a15, 0x10a17, G::Consonant), (0x10a19, 0x10a35, G::Consonant),
+    (0x10a38, 0x10a3a, G::Extend), (0x10a3f, 0x10a3f, G::ExtendLinker),
+    (0x10ae5, 0x10ae6, G::Extend), (0x10d24, 0x10d27, G::Extend), (0x10d69, 0x10d6d, G::Extend),
+    (0x10eab, 0x10eac, G::Extend), (0x10efa, 0x10eff, G::Extend), (0x10f46, 0x10f50, G::Extend),
+    (0x10f82, 0x10f85, G::Extend), (0x11000, 0x11000, G::SpacingMark),
+    (0x11001, 0x11001, G::Extend), (0x11002, 0x11002, G::SpacingMark),
+    (0x11038, 0x11046, G::Extend), (0x11070, 0x11070, G::Extend), (0x11073, 0x11074, G::Extend),
+    (0x1107f, 0x11081, G::Extend), (0x11082, 0x11082, G::SpacingMark),
+    (0x110b0, 0x110b2, G::SpacingMark), (0x110b3, 0x110b6, G::Extend),
+    (0x110b7, 0x110b8, G::SpacingMark), (0x110b9, 0x110ba, G::Extend),
+    (0x110bd, 0x110bd, G::Prepend), (0x110c2, 0x110c2, G::Extend), (0x110cd, 0x110cd, G::Prepend),
+    (0x11100, 0x11102, G::Extend), (0x11103, 0x11126, G::Consonant), (0x11127, 0x1112b, G::Extend),
+    (0x1112c, 0x1112c, G::SpacingMark), (0x1112d, 0x11132, G::Extend),
+    (0x11133, 0x11133, G::ExtendLinker), (0x11134, 0x11134, G::Extend),
+    (0x11144, 0x11144, G::Consonant), (0x11145, 0x11146, G::SpacingMark),
+    (0x11147, 0x11147, G::Consonant), (0x11173, 0x11173, G::Extend), (0x11180, 0x11181, G::Extend),
+    (0x11182, 0x11182, G::SpacingMark), (0x111b3, 0x111b5, G::SpacingMark),
+    (0x111b6, 0x111be, G::Extend), (0x111bf, 0x111bf, G::SpacingMark),
+    (0x111c0, 0x111c0, G::Extend), (0x111c2, 0x111c3, G::Prepend), (0x111c9, 0x111cc, G::Extend),
+    (0x111ce, 0x111ce, G::SpacingMark), (0x111cf, 0x111cf, G::Extend),
+    (0x1122c, 0x1122e, G::SpacingMark), (0x1122f, 0x11231, G::Extend),
+    (0x11232, 0x11233, G::SpacingMark), (0x11234, 0x11237, G::Extend),
+    (0x1123e, 0x1123e, G::Extend), (0x11241, 0x11241, G::Extend), (0x112df, 0x112df, G::Extend),
+    (0x112e0, 0x112e2, G::SpacingMark), (0x112e3, 0x112ea, G::Extend),
+    (0x11300, 0x11301, G::Extend), (0x11302, 0x11303, G::SpacingMark),
+    (0x1133b, 0x1133c, G::Extend), (0x1133e, 0x1133e, G::Extend),
+    (0x1133f, 0x1133f, G::SpacingMark), (0x11340, 0x11340, G::Extend),
+    (0x11341, 0x11344, G::SpacingMark), (0x11347, 0x11348, G::SpacingMark),
+    (0x1134b, 0x1134c, G::SpacingMark), (0x1134d, 0x1134d, G::Extend),
+    (0x11357, 0x11357, G::Extend), (0x11362, 0x11363, G::SpacingMark),
+    (0x11366, 0x1136c, G::Extend), (0x11370, 0x11374, G::Extend), (0x11380, 0x11389, G::Consonant),
+    (0x1138b, 0x1138b, G::Consonant), (0x1138e, 0x1138e, G::Consonant),
+    (0x11390, 0x113b5, G::Consonant), (0x113b8, 0x113b8, G::Extend),
+    (0x113b9, 0x113ba, G::SpacingMark), (0x113bb, 0x113c0, G::Extend),
+    (0x113c2, 0x113c2, G::Extend), (0x113c5, 0x113c5, G::Extend), (0x113c7, 0x113c9, G::Extend),
+    (0x113ca, 0x113ca, G::SpacingMark), (0x113cc, 0x113cd, G::SpacingMark),
+    (0x113ce, 0x113cf, G::Extend), (0x113d0, 0x113d0, G::ExtendLinker),
+    (0x113d1, 0x113d1, G::Prepend), (0x113d2, 0x113d2, G::Extend), (0x113e1, 0x113e2, G::Extend),
+    (0x11435, 0x11437, G::SpacingMark), (0x11438, 0x1143f, G::Extend),
+    (0x11440, 0x11441, G::SpacingMark), (0x11442, 0x11444, G::Extend),
+    (0x11445, 0x11445, G::SpacingMark), (0x11446, 0x11446, G::Extend),
+    (0x1145e, 0x1145e, G::Extend), (0x114b0, 0x114b0, G::Extend),
+    (0x114b1, 0x114b2, G::SpacingMark), (0x114b3, 0x114b8, G::Extend),
+    (0x114b9, 0x114b9, G::SpacingMark), (0x114ba, 0x114ba, G::Extend),
+    (0x114bb, 0x114bc, G::SpacingMark), (0x114bd, 0x114bd, G::Extend),
+    (0x114be, 0x114be, G::SpacingMark), (0x114bf, 0x114c0, G::Extend),
+    (0x114c1, 0x114c1, G::SpacingMark), (0x114c2, 0x114c3, G::Extend),
+    (0x115af, 0x115af, G::Extend), (0x115b0, 0x115b1, G::SpacingMark),
+    (0x115b2, 0x115b5, G::Extend), (0x115b8, 0x115bb, G::SpacingMark),
+    (0x115bc, 0x115bd, G::Extend), (0x115be, 0x115be, G::SpacingMark),
+    (0x115bf, 0x115c0, G::Extend), (0x115dc, 0x115dd, G::Extend),
+    (0x11630, 0x11632, G::SpacingMark), (0x11633, 0x1163a, G::Extend),
+    (0x1163b, 0x1163c, G::SpacingMark), (0x1163d, 0x1163d, G::Extend),
+    (0x1163e, 0x1163e, G::SpacingMark), (0x1163f, 0x11640, G::Extend),
+    (0x116ab, 0x116ab, G::Extend), (0x116ac, 0x116ac, G::SpacingMark),
+    (0x116ad, 0x116ad, G::Extend), (0x116ae, 0x116af, G::SpacingMark),
+    (0x116b0, 0x116b7, G::Extend), (0x1171d, 0x1171d, G::Extend),
+    (0x1171e, 0x1171e, G::SpacingMark), (0x1171f, 0x1171f, G::Extend),
+    (0x11722, 0x11725, G::Extend), (0x11726, 0x11726, G::SpacingMark),
+    (0x11727, 0x1172b, G::Extend), (0x1182c, 0x1182e, G::SpacingMark),
+    (0x1182f, 0x11837, G::Extend), (0x11838, 0x11838, G::SpacingMark),
+    (0x11839, 0x1183a, G::Extend), (0x11900, 0x11906, G::Consonant),
+    (0x11909, 0x11909, G::Consonant), (0x1190c, 0x11913, G::Consonant),
+    (0x11915, 0x11916, G::Consonant), (0x11918, 0x1192f, G::Consonant),
+    (0x11930, 0x11930, G::Extend), (0x11931, 0x11935, G::SpacingMark),
+    (0x11937, 0x11938, G::SpacingMark), (0x1193b, 0x1193d, G::Extend),
+    (0x1193e, 0x1193e, G::ExtendLinker), (0x1193f, 0x1193f, G::Prepend),
+    (0x11940, 0x11940, G::SpacingMark), (0x11941, 0x11941, G::Prepend),
+    (0x11942, 0x11942, G::SpacingMark), (0x11943, 0x11943, G::Extend),
+    (0x119d1, 0x119d3, G::SpacingMark), (0x119d4, 0x119d7, G::Extend),
+    (0x119da, 0x119db, G::Extend), (0x119dc, 0x119df, G::SpacingMark),
+    (0x119e0, 0x119e0, G::Extend), (0x119e4, 0x119e4, G::SpacingMark),
+    (0x11a00, 0x11a00, G::Consonant), (0x11a01, 0x11a0a, G::Extend),
+    (0x11a0b, 0x11a32, G::Consonant), (0x11a33, 0x11a38, G::Extend),
+    (0x11a39, 0x11a39, G::SpacingMark), (0x11a3b, 0x11a3e, G::Extend),
+    (0x11a47, 0x11a47, G::ExtendLinker), (0x11a50, 0x11a50, G::Consonant),
+    (0x11a51, 0x11a56, G::Extend), (0x11a57, 0x11a58, G::SpacingMark),
+    (0x11a59, 0x11a5b, G::Extend), (0x11a5c, 0x11a83, G::Consonant), (0x11a84, 0x11a89, G::Prepend),
+    (0x11a8a, 0x11a96, G::Extend), (0x11a97, 0x11a97, G::SpacingMark),
+    (0x11a98, 0x11a98, G::Extend), (0x11a99, 0x11a99, G::ExtendLinker),
+    (0x11b60, 0x11b60, G::Extend), (0x11b61, 0x11b61, G::SpacingMark),
+    (0x11b62, 0x11b64, G::Extend), (0x11b65, 0x11b65, G::SpacingMark),
+    (0x11b66, 0x11b66, G::Extend), (0x11b67, 0x11b67, G::SpacingMark),
+    (0x11c2f, 0x11c2f, G::SpacingMark), (0x11c30, 0x11c36, G::Extend),
+    (0x11c38, 0x11c3d, G::Extend), (0x11c3e, 0x11c3e, G::SpacingMark),
+    (0x11c3f, 0x11c3f, G::Extend), (0x11c92, 0x11ca7, G::Extend),
+    (0x11ca9, 0x11ca9, G::SpacingMark), (0x11caa, 0x11cb0, G::Extend),
+    (0x11cb1, 0x11cb1, G::SpacingMark), (0x11cb2, 0x11cb3, G::Extend),
+    (0x11cb4, 0x11cb4, G::SpacingMark), (0x11cb5, 0x11cb6, G::Extend),
+    (0x11d31, 0x11d36, G::Extend), (0x11d3a, 0x11d3a, G::Extend), (0x11d3c, 0x11d3d, G::Extend),
+    (0x11d3f, 0x11d45, G::Extend), (0x11d46, 0x11d46, G::Prepend), (0x11d47, 0x11d47, G::Extend),
+    (0x11d8a, 0x11d8e, G::SpacingMark), (0x11d90, 0x11d91, G::Extend),
+    (0x11d93, 0x11d94, G::SpacingMark), (0x11d95, 0x11d95, G::Extend),
+    (0x11d96, 0x11d96, G::SpacingMark), (0x11d97, 0x11d97, G::Extend),
+    (0x11ef3, 0x11ef4, G::Extend), (0x11ef5, 0x11ef6, G::SpacingMark),
+    (0x11f00, 0x11f01, G::Extend), (0x11f02, 0x11f02, G::Prepend),
+    (0x11f03, 0x11f03, G::SpacingMark), (0x11f04, 0x11f10, G::Consonant),
+    (0x11f12, 0x11f33, G::Consonant), (0x11f34, 0x11f35, G::SpacingMark),
+    (0x11f36, 0x11f3a, G::Extend), (0x11f3e, 0x11f3f, G::SpacingMark),
+    (0x11f40, 0x11f41, G::Extend), (0x11f42, 0x11f42, G::ExtendLinker),
+    (0x11f5a, 0x11f5a, G::Extend), (0x13430, 0x1343f, G::Control), (0x13440, 0x13440, G::Extend),
+    (0x13447, 0x13455, G::Extend), (0x1611e, 0x16129, G::Extend),
+    (0x1612a, 0x1612c, G::SpacingMark), (0x1612d, 0x1612f, G::Extend),
+    (0x16af0, 0x16af4, G::Extend), (0x16b30, 0x16b36, G::Extend), (0x16d63, 0x16d63, G::HangulV),
+    (0x16d67, 0x16d6a, G::HangulV), (0x16f4f, 0x16f4f, G::Extend),
+    (0x16f51, 0x16f87, G::SpacingMark), (0x16f8f, 0x16f92, G::Extend),
+    (0x16fe4, 0x16fe4, G::Extend), (0x16ff0, 0x16ff1, G::Extend), (0x1bc9d, 0x1bc9e, G::Extend),
+    (0x1bca0, 0x1bca3, G::Control), (0x1cf00, 0x1cf2d, G::Extend), (0x1cf30, 0x1cf46, G::Extend),
+    (0x1d165, 0x1d169, G::Extend), (0x1d16d, 0x1d172, G::Extend), (0x1d173, 0x1d17a, G::Control),
+    (0x1d17b, 0x1d182, G::Extend), (0x1d185, 0x1d18b, G::Extend), (0x1d1aa, 0x1d1ad, G::Extend),
+    (0x1d242, 0x1d244, G::Extend), (0x1da00, 0x1da36, G::Extend), (0x1da3b, 0x1da6c, G::Extend),
+    (0x1da75, 0x1da75, G::Extend), (0x1da84, 0x1da84, G::Extend), (0x1da9b, 0x1da9f, G::Extend),
+    (0x1daa1, 0x1daaf, G::Extend), (0x1e000, 0x1e006, G::Extend), (0x1e008, 0x1e018, G::Extend),
+    (0x1e01b, 0x1e021, G::Extend), (0x1e023, 0x1e024, G::Extend), (0x1e026, 0x1e02a, G::Extend),
+    (0x1e08f, 0x1e08f, G::Extend), (0x1e130, 0x1e136, G::Extend), (0x1e2ae, 0x1e2ae, G::Extend),
+    (0x1e2ec, 0x1e2ef, G::Extend), (0x1e4ec, 0x1e4ef, G::Extend), (0x1e5ee, 0x1e5ef, G::Extend),
+    (0x1e6e3, 0x1e6e3, G::Extend), (0x1e6e6, 0x1e6e6, G::Extend), (0x1e6ee, 0x1e6ef, G::Extend),
+    (0x1e6f5, 0x1e6f5, G::Extend), (0x1e8d0, 0x1e8d6, G::Extend), (0x1e944, 0x1e94a, G::Extend),
+    (0x1f004, 0x1f004, G::Pictographic), (0x1f02c, 0x1f02f, G::Pictographic),
+    (0x1f094, 0x1f09f, G::Pictographic), (0x1f0af, 0x1f0b0, G::Pictographic),
+    (0x1f0c0, 0x1f0c0, G::Pictographic), (0x1f0cf, 0x1f0d0, G::Pictographic),
+    (0x1f0f6, 0x1f0ff, G::Pictographic), (0x1f170, 0x1f171, G::Pictographic),
+    (0x1f17e, 0x1f17f, G::Pictographic), (0x1f18e, 0x1f18e, G::Pictographic),
+    (0x1f191, 0x1f19a, G::Pictographic), (0x1f1ae, 0x1f1e5, G::Pictographic),
+    (0x1f1e6, 0x1f1ff, G::RegionalIndicator), (0x1f201, 0x1f20f, G::Pictographic),
+    (0x1f21a, 0x1f21a, G::Pictographic), (0x1f22f, 0x1f22f, G::Pictographic),
+    (0x1f232, 0x1f23a, G::Pictographic), (0x1f23c, 0x1f23f, G::Pictographic),
+    (0x1f249, 0x1f25f, G::Pictographic), (0x1f266, 0x1f321, G::Pictographic),
+    (0x1f324, 0x1f393, G::Pictographic), (0x1f396, 0x1f397, G::Pictographic),
+    (0x1f399, 0x1f39b, G::Pictographic), (0x1f39e, 0x1f3f0, G::Pictographic),
+    (0x1f3f3, 0x1f3f5, G::Pictographic), (0x1f3f7, 0x1f3fa, G::Pictographic),
+    (0x1f3fb, 0x1f3ff, G::Extend), (0x1f400, 0x1f4fd, G::Pictographic),
+    (0x1f4ff, 0x1f53d, G::Pictographic), (0x1f549, 0x1f54e, G::Pictographic),
+    (0x1f550, 0x1f567, G::Pictographic), (0x1f56f, 0x1f570, G::Pictographic),
+    (0x1f573, 0x1f57a, G::Pictographic), (0x1f587, 0x1f587, G::Pictographic),
+    (0x1f58a, 0x1f58d, G::Pictographic), (0x1f590, 0x1f590, G::Pictographic),
+    (0x1f595, 0x1f596, G::Pictographic), (0x1f5a4, 0x1f5a5, G::Pictographic),
+    (0x1f5a8, 0x1f5a8, G::Pictographic), (0x1f5b1, 0x1f5b2, G::Pictographic),
+    (0x1f5bc, 0x1f5bc, G::Pictographic), (0x1f5c2, 0x1f5c4, G::Pictographic),
+    (0x1f5d1, 0x1f5d3, G::Pictographic), (0x1f5dc, 0x1f5de, G::Pictographic),
+    (0x1f5e1, 0x1f5e1, G::Pictographic), (0x1f5e3, 0x1f5e3, G::Pictographic),
+    (0x1f5e8, 0x1f5e8, G::Pictographic), (0x1f5ef, 0x1f5ef, G::Pictographic),
+    (0x1f5f3, 0x1f5f3, G::Pictographic), (0x1f5fa, 0x1f64f, G::Pictographic),
+    (0x1f680, 0x1f6c5, G::Pictographic), (0x1f6cb, 0x1f6d2, G::Pictographic),
+    (0x1f6d5, 0x1f6e5, G::Pictographic), (0x1f6e9, 0x1f6e9, G::Pictographic),
+    (0x1f6eb, 0x1f6f0, G::Pictographic), (0x1f6f3, 0x1f6ff, G::Pictographic),
+    (0x1f7da, 0x1f7ff, G::Pictographic), (0x1f80c, 0x1f80f, G::Pictographic),
+    (0x1f848, 0x1f84f, G::Pictographic), (0x1f85a, 0x1f85f, G::Pictographic),
+    (0x1f888, 0x1f88f, G::Pictographic), (0x1f8ae, 0x1f8af, G::Pictographic),
+    (0x1f8bc, 0x1f8bf, G::Pictographic), (0x1f8c2, 0x1f8cf, G::Pictographic),
+    (0x1f8d9, 0x1f8ff, G::Pictographic), (0x1f90c, 0x1f93a, G::Pictographic),
+    (0x1f93c, 0x1f945, G::Pictographic), (0x1f947, 0x1f9ff, G::Pictographic),
+    (0x1fa58, 0x1fa5f, G::Pictographic), (0x1fa6e, 0x1faff, G::Pictographic),
+    (0x1fc00, 0x1fffd, G::Pictographic), (0xe0000, 0xe001f, G::Control),
+    (0xe0020, 0xe007f, G::Extend), (0xe0080, 0xe00ff, G::Control), (0xe0100, 0xe01ef, G::Extend),
+    (0xe01f0, 0xe0fff, G::Control),
+];
+
+/// Non-printable chars: control, format, private use, surrogate and unassigned chars,
+/// and separators other than the ASCII space.
+pub(super) const NON_PRINTABLE_RANGES: &[(u32, u32)] = &[
+    (0x0, 0x1f), (0x7f, 0xa0), (0xad, 0xad), (0x378, 0x379), (0x380, 0x383), (0x38b, 0x38b),
+    (0x38d, 0x38d), (0x3a2, 0x3a2), (0x530, 0x530), (0x557, 0x558), (0x58b, 0x58c), (0x590, 0x590),
+    (0x5c8, 0x5cf), (0x5eb, 0x5ee), (0x5f5, 0x605), (0x61c, 0x61c), (0x6dd, 0x6dd), (0x70e, 0x70f),
+    (0x74b, 0x74c), (0x7b2, 0x7bf), (0x7fb, 0x7fc), (0x82e, 0x82f), (0x83f, 0x83f), (0x85c, 0x85d),
+    (0x85f, 0x85f), (0x86b, 0x86f), (0x890, 0x896), (0x8e2, 0x8e2), (0x984, 0x984), (0x98d, 0x98e),
+    (0x991, 0x992), (0x9a9, 0x9a9), (0x9b1, 0x9b1), (0x9b3, 0x9b5), (0x9ba, 0x9bb), (0x9c5, 0x9c6),
+    (0x9c9, 0x9ca), (0x9cf, 0x9d6), (0x9d8, 0x9db), (0x9de, 0x9de), (0x9e4, 0x9e5), (0x9ff, 0xa00),
+    (0xa04, 0xa04), (0xa0b, 0xa0e), (0xa11, 0xa12), (0xa29, 0xa29), (0xa31, 0xa31), (0xa34, 0xa34),
+    (0xa37, 0xa37), (0xa3a, 0xa3b), (0xa3d, 0xa3d), (0xa43, 0xa46), (0xa49, 0xa4a), (0xa4e, 0xa50),
+    (0xa52, 0xa58), (0xa5d, 0xa5d), (0xa5f, 0xa65), (0xa77, 0xa80), (0xa84, 0xa84), (0xa8e, 0xa8e),
+    (0xa92, 0xa92), (0xaa9, 0xaa9), (0xab1, 0xab1), (0xab4, 0xab4), (0xaba, 0xabb), (0xac6, 0xac6),
+    (0xaca, 0xaca), (0xace, 0xacf), (0xad1, 0xadf), (0xae4, 0xae5), (0xaf2, 0xaf8), (0xb00, 0xb00),
+    (0xb04, 0xb04), (0xb0d, 0xb0e), (0xb11, 0xb12), (0xb29, 0xb29), (0xb31, 0xb31), (0xb34, 0xb34),
+    (0xb3a, 0xb3b), (0xb45, 0xb46), (0xb49, 0xb4a), (0xb4e, 0xb54), (0xb58, 0xb5b), (0xb5e, 0xb5e),
+    (0xb64, 0xb65), (0xb78, 0xb81), (0xb84, 0xb84), (0xb8b, 0xb8d), (0xb91, 0xb91), (0xb96, 0xb98),
+    (0xb9b, 0xb9b), (0xb9d, 0xb9d), (0xba0, 0xba2), (0xba5, 0xba7), (0xbab, 0xbad), (0xbba, 0xbbd),
+    (0xbc3, 0xbc5), (0xbc9, 0xbc9), (0xbce, 0xbcf), (0xbd1, 0xbd6), (0xbd8, 0xbe5), (0xbfb, 0xbff),
+    (0xc0d, 0xc0d), (0xc11, 0xc11), (0xc29, 0xc29), (0xc3a, 0xc3b), (0xc45, 0xc45), (0xc49, 0xc49),
+    (0xc4e, 0xc54), (0xc57, 0xc57), (0xc5b, 0xc5b), (0xc5e, 0xc5f), (0xc64, 0xc65), (0xc70, 0xc76),
+    (0xc8d, 0xc8d), (0xc91, 0xc91), (0xca9, 0xca9), (0xcb4, 0xcb4), (0xcba, 0xcbb), (0xcc5, 0xcc5),
+    (0xcc9, 0xcc9), (0xcce, 0xcd4), (0xcd7, 0xcdb), (0xcdf, 0xcdf), (0xce4, 0xce5), (0xcf0, 0xcf0),
+    (0xcf4, 0xcff), (0xd0d, 0xd0d), (0xd11, 0xd11), (0xd45, 0xd45), (0xd49, 0xd49), (0xd50, 0xd53),
+    (0xd64, 0xd65), (0xd80, 0xd80), (0xd84, 0xd84), (0xd97, 0xd99), (0xdb2, 0xdb2), (0xdbc, 0xdbc),
+    (0xdbe, 0xdbf), (0xdc7, 0xdc9), (0xdcb, 0xdce), (0xdd5, 0xdd5), (0xdd7, 0xdd7), (0xde0, 0xde5),
+    (0xdf0, 0xdf1), (0xdf5, 0xe00), (0xe3b, 0xe3e), (0xe5c, 0xe80), (0xe83, 0xe83), (0xe85, 0xe85),
+    (0xe8b, 0xe8b), (0xea4, 0xea4), (0xea6, 0xea6), (0xebe, 0xebf), (0xec5, 0xec5), (0xec7, 0xec7),
+    (0xecf, 0xecf), (0xeda, 0xedb), (0xee0, 0xeff), (0xf48, 0xf48), (0xf6d, 0xf70), (0xf98, 0xf98),
+    (0xfbd, 0xfbd), (0xfcd, 0xfcd), (0xfdb, 0xfff), (0x10c6, 0x10c6), (0x10c8, 0x10cc),
+    (0x10ce, 0x10cf), (0x1249, 0x1249), (0x124e, 0x124f), (0x1257, 0x1257), (0x1259, 0x1259),
+    (0x125e, 0x125f), (0x1289, 0x1289), (0x128e, 0x128f), (0x12b1, 0x12b1), (0x12b6, 0x12b7),
+    (0x12bf, 0x12bf), (0x12c1, 0x12c1), (0x12c6, 0x12c7), (0x12d7, 0x12d7), (0x1311, 0x1311),
+    (0x1316, 0x1317), (0x135b, 0x135c), (0x137d, 0x137f), (0x139a, 0x139f), (0x13f6, 0x13f7),
+    (0x13fe, 0x13ff), (0x1680, 0x1680), (0x169d, 0x169f), (0x16f9, 0x16ff), (0x1716, 0x171e),
+    (0x1737, 0x173f), (0x1754, 0x175f), (0x176d, 0x176d), (0x1771, 0x1771), (0x1774, 0x177f),
+    (0x17de, 0x17df), (0x17ea, 0x17ef), (0x17fa, 0x17ff), (0x180e, 0x180e), (0x181a, 0x181f),
+    (0x1879, 0x187f), (0x18ab, 0x18af), (0x18f6, 0x18ff), (0x191f, 0x191f), (0x192c, 0x192f),
+    (0x193c, 0x193f), (0x1941, 0x1943), (0x196e, 0x196f), (0x1975, 0x197f), (0x19ac, 0x19af),
+    (0x19ca, 0x19cf), (0x19db, 0x19dd), (0x1a1c, 0x1a1d), (0x1a5f, 0x1a5f), (0x1a7d, 0x1a7e),
+    (0x1a8a, 0x1a8f), (0x1a9a, 0x1a9f), (0x1aae, 0x1aaf), (0x1ade, 0x1adf), (0x1aec, 0x1aff),
+    (0x1b4d, 0x1b4d), (0x1bf4, 0x1bfb), (0x1c38, 0x1c3a), (0x1c4a, 0x1c4c), (0x1c8b, 0x1c8f),
+    (0x1cbb, 0x1cbc), (0x1cc8, 0x1ccf), (0x1cfb, 0x1cff), (0x1f16, 0x1f17), (0x1f1e, 0x1f1f),
+    (0x1f46, 0x1f47), (0x1f4e, 0x1f4f), (0x1f58, 0x1f58), (0x1f5a, 0x1f5a), (0x1f5c, 0x1f5c),
+    (0x1f5e, 0x1f5e), (0x1f7e, 0x1f7f), (0x1fb5, 0x1fb5), (0x1fc5, 0x1fc5), (0x1fd4, 0x1fd5),
+    (0x1fdc, 0x1fdc), (0x1ff0, 0x1ff1), (0x1ff5, 0x1ff5), (0x1fff, 0x200f), (0x2028, 0x202f),
+    (0x205f, 0x206f), (0x2072, 0x2073), (0x208f, 0x208f), (0x209d, 0x209f), (0x20c2, 0x20cf),
+    (0x20f1, 0x20ff), (0x218c, 0x218f), (0x242a, 0x243f), (0x244b, 0x245f), (0x2b74, 0x2b75),
+    (0x2cf4, 0x2cf8), (0x2d26, 0x2d26), (0x2d28, 0x2d2c), (0x2d2e, 0x2d2f), (0x2d68, 0x2d6e),
+    (0x2d71, 0x2d7e), (0x2d97, 0x2d9f), (0x2da7, 0x2da7), (0x2daf, 0x2daf), (0x2db7, 0x2db7),
+    (0x2dbf, 0x2dbf), (0x2dc7, 0x2dc7), (0x2dcf, 0x2dcf), (0x2dd7, 0x2dd7), (0x2ddf, 0x2ddf),
+    (0x2e5e, 0x2e7f), (0x2e9a, 0x2e9a), (0x2ef4, 0x2eff), (0x2fd6, 0x2fef), (0x3000, 0x3000),
+    (0x3040, 0x3040), (0x3097, 0x3098), (0x3100, 0x3104), (0x3130, 0x3130), (0x318f, 0x318f),
+    (0x31e6, 0x31ee), (0x321f, 0x321f), (0xa48d, 0xa48f), (0xa4c7, 0xa4cf), (0xa62c, 0xa63f),
+    (0xa6f8, 0xa6ff), (0xa7dd, 0xa7f0), (0xa82d, 0xa82f), (0xa83a, 0xa83f), (0xa878, 0xa87f),
+    (0xa8c6, 0xa8cd), (0xa8da, 0xa8df), (0xa954, 0xa95e), (0xa97d, 0xa97f), (0xa9ce, 0xa9ce),
+    (0xa9da, 0xa9dd), (0xa9ff, 0xa9ff), (0xaa37, 0xaa3f), (0xaa4e, 0xaa4f), (0xaa5a, 0xaa5b),
+    (0xaac3, 0xaada), (0xaaf7, 0xab00), (0xab07, 0xab08), (0xab0f, 0xab10), (0xab17, 0xab1f),
+    (0xab27, 0xab27), (0xab2f, 0xab2f), (0xab6c, 0xab6f), (0xabee, 0xabef), (0xabfa, 0xabff),
+    (0xd7a4, 0xd7af), (0xd7c7, 0xd7ca), (0xd7fc, 0xf8ff), (0xfa6e, 0xfa6f), (0xfada, 0xfaff),
+    (0xfb07, 0xfb12), (0xfb18, 0xfb1c), (0xfb37, 0xfb37), (0xfb3d, 0xfb3d), (0xfb3f, 0xfb3f),
+    (0xfb42, 0xfb42), (0xfb45, 0xfb45), (0xfdd0, 0xfdef), (0xfe1a, 0xfe1f), (0xfe53, 0xfe53),
+    (0xfe67, 0xfe67), (0xfe6c, 0xfe6f), (0xfe75, 0xfe75), (0xfefd, 0xff00), (0xffbf, 0xffc1),
+    (0xffc8, 0xffc9), (0xffd0, 0xffd1), (0xffd8, 0xffd9), (0xffdd, 0xffdf), (0xffe7, 0xffe7),
+    (0xffef, 0xfffb), (0xfffe, 0xffff), (0x1000c, 0x1000c), (0x10027, 0x10027), (0x1003b, 0x1003b),
+    (0x1003e, 0x1003e), (0x1004e, 0x1004f), (0x1005e, 0x1007f), (0x100fb, 0x100ff),
+    (0x10103, 0x10106), (0x10134, 0x10136), (0x1018f, 0x1018f), (0x1019d, 0x1019f),
+    (0x101a1, 0x101cf), (0x101fe, 0x1027f), (0x1029d, 0x1029f), (0x102d1, 0x102df),
+    (0x102fc, 0x102ff), (0x10324, 0x1032c), (0x1034b, 0x1034f), (0x1037b, 0x1037f),
+    (0x1039e, 0x1039e), (0x103c4, 0x103c7), (0x103d6, 0x103ff), (0x1049e, 0x1049f),
+    (0x104aa, 0x104af), (0x104d4, 0x104d7), (0x104fc, 0x104ff), (0x10528, 0x1052f),
+    (0x10564, 0x1056e), (0x1057b, 0x1057b), (0x1058b, 0x1058b), (0x10593, 0x10593),
+    (0x10596, 0x10596), (0x105a2, 0x105a2), (0x105b2, 0x105b2), (0x105ba, 0x105ba),
+    (0x105bd, 0x105bf), (0x105f4, 0x105ff), (0x10737, 0x1073f), (0x10756, 0x1075f),
+    (0x10768, 0x1077f), (0x10786, 0x10786), (0x107b1, 0x107b1), (0x107bb, 0x107ff),
+    (0x10806, 0x10807), (0x10809, 0x10809), (0x10836, 0x10836), (0x10839, 0x1083b),
+    (0x1083d, 0x1083e), (0x10856, 0x10856), (0x1089f, 0x108a6), (0x108b0, 0x108df),
+    (0x108f3, 0x108f3), (0x108f6, 0x108fa), (0x1091c, 0x1091e), (0x1093a, 0x1093e),
+    (0x1095a, 0x1097f), (0x109b8, 0x109bb), (0x109d0, 0x109d1), (0x10a04, 0x10a04),
+    (0x10a07, 0x10a0b), (0x10a14, 0x10a14), (0x10a18, 0x10a18), (0x10a36, 0x10a37),
+    (0x10a3b, 0x10a3e), (0x10a49, 0x10a4f), (0x10a59, 0x10a5f), (0x10aa0, 0x10abf),
+    (0x10ae7, 0x10aea), (0x10af7, 0x10aff), (0x10b36, 0x10b38), (0x10b56, 0x10b57),
+    (0x10b73, 0x10b77), (0x10b92, 0x10b98), (0x10b9d, 0x10ba8), (0x10bb0, 0x10bff),
+    (0x10c49, 0x10c7f), (0x10cb3, 0x10cbf), (0x10cf3, 0x10cf9), (0x10d28, 0x10d2f),
+    (0x10d3a, 0x10d3f), (0x10d66, 0x10d68), (0x10d86, 0x10d8d), (0x10d90, 0x10e5f),
+    (0x10e7f, 0x10e7f), (0x10eaa, 0x10eaa), (0x10eae, 0x10eaf), (0x10eb2, 0x10ec1),
+    (0x10ec8, 0x10ecf), (0x10ed9, 0x10ef9), (0x10f28, 0x10f2f), (0x10f5a, 0x10f6f),
+    (0x10f8a, 0x10faf), (0x10fcc, 0x10fdf), (0x10ff7, 0x10fff), (0x1104e, 0x11051),
+    (0x11076, 0x1107e), (0x110bd, 0x110bd), (0x110c3, 0x110cf), (0x110e9, 0x110ef),
+    (0x110fa, 0x110ff), (0x11135, 0x11135), (0x11148, 0x1114f), (0x11177, 0x1117f),
+    (0x111e0, 0x111e0), (0x111f5, 0x111ff), (0x11212, 0x11212), (0x11242, 0x1127f),
+    (0x11287, 0x11287), (0x11289, 0x11289), (0x1128e, 0x1128e), (0x1129e, 0x1129e),
+    (0x112aa, 0x112af), (0x112eb, 0x112ef), (0x112fa, 0x112ff), (0x11304, 0x11304),
+    (0x1130d, 0x1130e), (0x11311, 0x11312), (0x11329, 0x11329), (0x11331, 0x11331),
+    (0x11334, 0x11334), (0x1133a, 0x1133a), (0x11345, 0x11346), (0x11349, 0x1134a),
+    (0x1134e, 0x1134f), (0x11351, 0x11356), (0x11358, 0x1135c), (0x11364, 0x11365),
+    (0x1136d, 0x1136f), (0x11375, 0x1137f), (0x1138a, 0x1138a), (0x1138c, 0x1138d),
+    (0x1138f, 0x1138f), (0x113b6, 0x113b6), (0x113c1, 0x113c1), (0x113c3, 0x113c4),
+    (0x113c6, 0x113c6), (0x113cb, 0x113cb), (0x113d6, 0x113d6), (0x113d9, 0x113e0),
+    (0x113e3, 0x113ff), (0x1145c, 0x1145c), (0x11462, 0x1147f), (0x114c8, 0x114cf),
+    (0x114da, 0x1157f), (0x115b6, 0x115b7), (0x115de, 0x115ff), (0x11645, 0x1164f),
+    (0x1165a, 0x1165f), (0x1166d, 0x1167f), (0x116ba, 0x116bf), (0x116ca, 0x116cf),
+    (0x116e4, 0x116ff), (0x1171b, 0x1171c), (0x1172c, 0x1172f), (0x11747, 0x117ff),
+    (0x1183c, 0x1189f), (0x118f3, 0x118fe), (0x11907, 0x11908), (0x1190a, 0x1190b),
+    (0x11914, 0x11914), (0x11917, 0x11917), (0x11936, 0x11936), (0x11939, 0x1193a),
+    (0x11947, 0x1194f), (0x1195a, 0x1199f), (0x119a8, 0x119a9), (0x119d8, 0x119d9),
+    (0x119e5, 0x119ff), (0x11a48, 0x11a4f), (0x11aa3, 0x11aaf), (0x11af9, 0x11aff),
+    (0x11b0a, 0x11b5f), (0x11b68, 0x11bbf), (0x11be2, 0x11bef), (0x11bfa, 0x11bff),
+    (0x11c09, 0x11c09), (0x11c37, 0x11c37), (0x11c46, 0x11c4f), (0x11c6d, 0x11c6f),
+    (0x11c90, 0x11c91), (0x11ca8, 0x11ca8), (0x11cb7, 0x11cff), (0x11d07, 0x11d07),
+    (0x11d0a, 0x11d0a), (0x11d37, 0x11d39), (0x11d3b, 0x11d3b), (0x11d3e, 0x11d3e),
+    (0x11d48, 0x11d4f), (0x11d5a, 0x11d5f), (0x11d66, 0x11d66), (0x11d69, 0x11d69),
+    (0x11d8f, 0x11d8f), (0x11d92, 0x11d92), (0x11d99, 0x11d9f), (0x11daa, 0x11daf),
+    (0x11ddc, 0x11ddf), (0x11dea, 0x11edf), (0x11ef9, 0x11eff), (0x11f11, 0x11f11),
+    (0x11f3b, 0x11f3d), (0x11f5b, 0x11faf), (0x11fb1, 0x11fbf), (0x11ff2, 0x11ffe),
+    (0x1239a, 0x123ff), (0x1246f, 0x1246f), (0x12475, 0x1247f), (0x12544, 0x12f8f),
+    (0x12ff3, 0x12fff), (0x13430, 0x1343f), (0x13456, 0x1345f), (0x143fb, 0x143ff),
+    (0x14647, 0x160ff), (0x1613a, 0x167ff), (0x16a39, 0x16a3f), (0x16a5f, 0x16a5f),
+    (0x16a6a, 0x16a6d), (0x16abf, 0x16abf), (0x16aca, 0x16acf), (0x16aee, 0x16aef),
+    (0x16af6, 0x16aff), (0x16b46, 0x16b4f), (0x16b5a, 0x16b5a), (0x16b62, 0x16b62),
+    (0x16b78, 0x16b7c), (0x16b90, 0x16d3f), (0x16d7a, 0x16e3f), (0x16e9b, 0x16e9f),
+    (0x16eb9, 0x16eba), (0x16ed4, 0x16eff), (0x16f4b, 0x16f4e), (0x16f88, 0x16f8e),
+    (0x16fa0, 0x16fdf), (0x16fe5, 0x16fef), (0x16ff7, 0x16fff), (0x18cd6, 0x18cfe),
+    (0x18d1f, 0x18d7f), (0x18df3, 0x1afef), (0x1aff4, 0x1aff4), (0x1affc, 0x1affc),
+    (0x1afff, 0x1afff), (0x1b123, 0x1b131), (0x1b133, 0x1b14f), (0x1b153, 0x1b154),
+    (0x1b156, 0x1b163), (0x1b168, 0x1b16f), (0x1b2fc, 0x1bbff), (0x1bc6b, 0x1bc6f),
+    (0x1bc7d, 0x1bc7f), (0x1bc89, 0x1bc8f), (0x1bc9a, 0x1bc9b), (0x1bca0, 0x1cbff),
+    (0x1ccfd, 0x1ccff), (0x1ceb4, 0x1ceb9), (0x1ced1, 0x1cedf), (0x1cef1, 0x1ceff),
+    (0x1cf2e, 0x1cf2f), (0x1cf47, 0x1cf4f), (0x1cfc4, 0x1cfff), (0x1d0f6, 0x1d0ff),
+    (0x1d127, 0x1d128), (0x1d173, 0x1d17a), (0x1d1eb, 0x1d1ff), (0x1d246, 0x1d2bf),
+    (0x1d2d4, 0x1d2df), (0x1d2f4, 0x1d2ff), (0x1d357, 0x1d35f), (0x1d379, 0x1d3ff),
+    (0x1d455, 0x1d455), (0x1d49d, 0x1d49d), (0x1d4a0, 0x1d4a1), (0x1d4a3, 0x1d4a4),
+    (0x1d4a7, 0x1d4a8), (0x1d4ad, 0x1d4ad), (0x1d4ba, 0x1d4ba), (0x1d4bc, 0x1d4bc),
+    (0x1d4c4, 0x1d4c4), (0x1d506, 0x1d506), (0x1d50b, 0x1d50c), (0x1d515, 0x1d515),
+    (0x1d51d, 0x1d51d), (0x1d53a, 0x1d53a), (0x1d53f, 0x1d53f), (0x1d545, 0x1d545),
+    (0x1d547, 0x1d549), (0x1d551, 0x1d551), (0x1d6a6, 0x1d6a7), (0x1d7cc, 0x1d7cd),
+    (0x1da8c, 0x1da9a), (0x1daa0, 0x1daa0), (0x1dab0, 0x1deff), (0x1df1f, 0x1df24),
+    (0x1df2b, 0x1dfff), (0x1e007, 0x1e007), (0x1e019, 0x1e01a), (0x1e022, 0x1e022),
+    (0x1e025, 0x1e025), (0x1e02b, 0x1e02f), (0x1e06e, 0x1e08e), (0x1e090, 0x1e0ff),
+    (0x1e12d, 0x1e12f), (0x1e13e, 0x1e13f), (0x1e14a, 0x1e14d), (0x1e150, 0x1e28f),
+    (0x1e2af, 0x1e2bf), (0x1e2fa, 0x1e2fe), (0x1e300, 0x1e4cf), (0x1e4fa, 0x1e5cf),
+    (0x1e5fb, 0x1e5fe), (0x1e600, 0x1e6bf), (0x1e6df, 0x1e6df), (0x1e6f6, 0x1e6fd),
+    (0x1e700, 0x1e7df), (0x1e7e7, 0x1e7e7), (0x1e7ec, 0x1e7ec), (0x1e7ef, 0x1e7ef),
+    (0x1e7ff, 0x1e7ff), (0x1e8c5, 0x1e8c6), (0x1e8d7, 0x1e8ff), (0x1e94c, 0x1e94f),
+    (0x1e95a, 0x1e95d), (0x1e960, 0x1ec70), (0x1ecb5, 0x1ed00), (0x1ed3e, 0x1edff),
+    (0x1ee04, 0x1ee04), (0x1ee20, 0x1ee20), (0x1ee23, 0x1ee23), (0x1ee25, 0x1ee26),
+    (0x1ee28, 0x1ee28), (0x1ee33, 0x1ee33), (0x1ee38, 0x1ee38), (0x1ee3a, 0x1ee3a),
+    (0x1ee3c, 0x1ee41), (0x1ee43, 0x1ee46), (0x1ee48, 0x1ee48), (0x1ee4a, 0x1ee4a),
+    (0x1ee4c, 0x1ee4c), (0x1ee50, 0x1ee50), (0x1ee53, 0x1ee53), (0x1ee55, 0x1ee56),
+    (0x1ee58, 0x1ee58), (0x1ee5a, 0x1ee5a), (0x1ee5c, 0x1ee5c), (0x1ee5e, 0x1ee5e),
+    (0x1ee60, 0x1ee60), (0x1ee63, 0x1ee63), (0x1ee65, 0x1ee66), (0x1ee6b, 0x1ee6b),
+    (0x1ee73, 0x1ee73), (0x1ee78, 0x1ee78), (0x1ee7d, 0x1ee7d), (0x1ee7f, 0x1ee7f),
+    (0x1ee8a, 0x1ee8a), (0x1ee9c, 0x1eea0), (0x1eea4, 0x1eea4), (0x1eeaa, 0x1eeaa),
+    (0x1eebc, 0x1eeef), (0x1eef2, 0x1efff), (0x1f02c, 0x1f02f), (0x1f094, 0x1f09f),
+    (0x1f0af, 0x1f0b0), (0x1f0c0, 0x1f0c0), (0x1f0d0, 0x1f0d0), (0x1f0f6, 0x1f0ff),
+    (0x1f1ae, 0x1f1e5), (0x1f203, 0x1f20f), (0x1f23c, 0x1f23f), (0x1f249, 0x1f24f),
+    (0x1f252, 0x1f25f), (0x1f266, 0x1f2ff), (0x1f6d9, 0x1f6db), (0x1f6ed, 0x1f6ef),
+    (0x1f6fd, 0x1f6ff), (0x1f7da, 0x1f7df), (0x1f7ec, 0x1f7ef), (0x1f7f1, 0x1f7ff),
+    (0x1f80c, 0x1f80f), (0x1f848, 0x1f84f), (0x1f85a, 0x1f85f), (0x1f888, 0x1f88f),
+    (0x1f8ae, 0x1f8af), (0x1f8bc, 0x1f8bf), (0x1f8c2, 0x1f8cf), (0x1f8d9, 0x1f8ff),
+    (0x1fa58, 0x1fa5f), (0x1fa6e, 0x1fa6f), (0x1fa7d, 0x1fa7f), (0x1fa8b, 0x1fa8d),
+    (0x1fac7, 0x1fac7), (0x1fac9, 0x1facc), (0x1fadd, 0x1fade), (0x1faeb, 0x1faee),
+    (0x1faf9, 0x1faff), (0x1fb93, 0x1fb93), (0x1fbfb, 0x1ffff), (0x2a6e0, 0x2a6ff),
+    (0x2b81e, 0x2b81f), (0x2ceae, 0x2ceaf), (0x2ebe1, 0x2ebef), (0x2ee5e, 0x2f7ff),
+    (0x2fa1e, 0x2ffff), (0x3134b, 0x3134f), (0x3347a, 0xe00ff), (0xe01f0, 0x10ffff),
+];
+
+/// Chars occupying 2 columns when displayed (`East_Asian_Width` is `W` or `F`).
+pub(super) const WIDE_RANGES: &[(u32, u32)] = &[
+    (0x1100, 0x115f), (0x231a, 0x231b), (0x2329, 0x232a), (0x23e9, 0x23ec), (0x23f0, 0x23f0),
+    (0x23f3, 0x23f3), (0x25fd, 0x25fe), (0x2614, 0x2615), (0x2630, 0x2637), (0x2648, 0x2653),
+    (0x267f, 0x267f), (0x268a, 0x268f), (0x2693, 0x2693), (0x26a1, 0x26a1), (0x26aa, 0x26ab),
+    (0x26bd, 0x26be), (0x26c4, 0x26c5), (0x26ce, 0x26ce), (0x26d4, 0x26d4), (0x26ea, 0x26ea),
+    (0x26f2, 0x26f3), (0x26f5, 0x26f5), (0x26fa, 0x26fa), (0x26fd, 0x26fd), (0x2705, 0x2705),
+    (0x270a, 0x270b), (0x2728, 0x2728), (0x274c, 0x274c), (0x274e, 0x274e), (0x2753, 0x2755),
+    (0x2757, 0x2757), (0x2795, 0x2797), (0x27b0, 0x27b0), (0x27bf, 0x27bf), (0x2b1b, 0x2b1c),
+    (0x2b50, 0x2b50), (0x2b55, 0x2b55), (0x2e80, 0x2e99), (0x2e9b, 0x2ef3), (0x2f00, 0x2fd5),
+    (0x2ff0, 0x303e), (0x3041, 0x3096), (0x3099, 0x30ff), (0x3105, 0x312f), (0x3131, 0x318e),
+    (0x3190, 0x31e5), (0x31ef, 0x321e), (0x3220, 0x3247), (0x3250, 0xa48c), (0xa490, 0xa4c6),
+    (0xa960, 0xa97c), (0xac00, 0xd7a3), (0xf900, 0xfaff), (0xfe10, 0xfe19), (0xfe30, 0xfe52),
+    (0xfe54, 0xfe66), (0xfe68, 0xfe6b), (0xff01, 0xff60), (0xffe0, 0xffe6), (0x16fe0, 0x16fe4),
+    (0x16ff0, 0x16ff6), (0x17000, 0x18cd5), (0x18cff, 0x18d1e), (0x18d80, 0x18df2),
+    (0x1aff0, 0x1aff3), (0x1aff5, 0x1affb), (0x1affd, 0x1affe), (0x1b000, 0x1b122),
+    (0x1b132, 0x1b132), (0x1b150, 0x1b152), (0x1b155, 0x1b155), (0x1b164, 0x1b167),
+    (0x1b170, 0x1b2fb), (0x1d300, 0x1d356), (0x1d360, 0x1d376), (0x1f004, 0x1f004),
+    (0x1f0cf, 0x1f0cf), (0x1f18e, 0x1f18e), (0x1f191, 0x1f19a), (0x1f200, 0x1f202),
+    (0x1f210, 0x1f23b), (0x1f240, 0x1f248), (0x1f250, 0x1f251), (0x1f260, 0x1f265),
+    (0x1f300, 0x1f320), (0x1f32d, 0x1f335), (0x1f337, 0x1f37c), (0x1f37e, 0x1f393),
+    (0x1f3a0, 0x1f3ca), (0x1f3cf, 0x1f3d3), (0x1f3e0, 0x1f3f0), (0x1f3f4, 0x1f3f4),
+    (0x1f3f8, 0x1f43e), (0x1f440, 0x1f440), (0x1f442, 0x1f4fc), (0x1f4ff, 0x1f53d),
+    (0x1f54b, 0x1f54e), (0x1f550, 0x1f567), (0x1f57a, 0x1f57a), (0x1f595, 0x1f596),
+    (0x1f5a4, 0x1f5a4), (0x1f5fb, 0x1f64f), (0x1f680, 0x1f6c5), (0x1f6cc, 0x1f6cc),
+    (0x1f6d0, 0x1f6d2), (0x1f6d5, 0x1f6d8), (0x1f6dc, 0x1f6df), (0x1f6eb, 0x1f6ec),
+    (0x1f6f4, 0x1f6fc), (0x1f7e0, 0x1f7eb), (0x1f7f0, 0x1f7f0), (0x1f90c, 0x1f93a),
+    (0x1f93c, 0x1f945), (0x1f947, 0x1f9ff), (0x1fa70, 0x1fa7c), (0x1fa80, 0x1fa8a),
+    (0x1fa8e, 0x1fac6), (0x1fac8, 0x1fac8), (0x1facd, 0x1fadc), (0x1fadf, 0x1faea),
+    (0x1faef, 0x1faf8), (0x20000, 0x2fffd), (0x30000, 0x3fffd),
+];
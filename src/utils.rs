@@ -1,6 +1,11 @@
 //! Miscellaneous utils.
 
-use core::slice;
+use core::{slice, str};
+
+use crate::{
+    unicode::{GraphemeBreaks, MAX_GRAPHEME_CHARS},
+    ArgumentWrapper, CompileArgs, MaxLength,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum ClippedStr<'a> {
@@ -37,11 +42,148 @@ impl<'a> ClippedStr<'a> {
             Self::Full(bytes)
         }
     }
+
+    /// Returns bytes corresponding to first `cluster_count` grapheme clusters in `s`. If `s` contains
+    /// less clusters, it's returned in full.
+    pub const fn with_graphemes(s: &'a str, mut cluster_count: usize) -> Self {
+        let s_bytes = s.as_bytes();
+        let mut pos = 0;
+        let mut breaks = GraphemeBreaks::START;
+        let mut chars_in_cluster = 0;
+        while pos < s_bytes.len() {
+            let (c, char_len) = decode_char(s_bytes, pos);
+            let (next_breaks, is_break) = breaks.push(c);
+            if is_break || chars_in_cluster == MAX_GRAPHEME_CHARS {
+                if cluster_count == 0 {
+                    break;
+                }
+                cluster_count -= 1;
+                chars_in_cluster = 0;
+            }
+
+            chars_in_cluster += 1;
+            breaks = next_breaks;
+            pos += char_len;
+        }
+
+        // SAFETY: Slicing a byte slice with length being in bounds is safe.
+        let bytes = unsafe { slice::from_raw_parts(s_bytes.as_ptr(), pos) };
+        if pos < s_bytes.len() {
+            Self::Clipped(bytes)
+        } else {
+            Self::Full(bytes)
+        }
+    }
+
+    /// Returns bytes corresponding to the maximum number of first chars in `s` that fit into `max_bytes`.
+    /// If `s` fits into `max_bytes`, it's returned in full.
+    pub const fn with_byte_budget(s: &'a str, max_bytes: usize) -> Self {
+        let s_bytes = s.as_bytes();
+        if s_bytes.len() <= max_bytes {
+            return Self::Full(s_bytes);
+        }
+        let mut pos = max_bytes;
+        // Skip UTF-8 continuation bytes so that the char at `pos` is not split.
+        while pos > 0 && s_bytes[pos] >> 6 == 0b_10 {
+            pos -= 1;
+        }
+        // SAFETY: Slicing a byte slice with length being in bounds is safe.
+        Self::Clipped(unsafe { slice::from_raw_parts(s_bytes.as_ptr(), pos) })
+    }
+}
+
+/// Returns the longest prefix of `s` no longer than `max_len` bytes that ends on a word boundary,
+/// i.e., before ASCII whitespace or after ASCII punctuation, with trailing whitespace trimmed.
+/// If there is no such non-empty prefix, returns the first `max_len` bytes of `s`.
+/// `max_len` must be less than `s.len()` and lie on a char boundary.
+pub(crate) const fn clip_at_word_boundary(s: &str, max_len: usize) -> &[u8] {
+    let s_bytes = s.as_bytes();
+    let mut pos = max_len;
+    // Since the checked bytes are ASCII, `pos` always lies on a char boundary when the loop terminates.
+    while pos > 0 {
+        if s_bytes[pos].is_ascii_whitespace() || s_bytes[pos - 1].is_ascii_punctuation() {
+            break;
+        }
+        pos -= 1;
+    }
+    while pos > 0 && s_bytes[pos - 1].is_ascii_whitespace() {
+        pos -= 1;
+    }
+    if pos == 0 {
+        pos = max_len;
+    }
+    // SAFETY: Slicing a byte slice with length being in bounds is safe.
+    unsafe { slice::from_raw_parts(s_bytes.as_ptr(), pos) }
+}
+
+/// Returns bytes corresponding to last `char_count` chars in `s`. If `s` contains less chars,
+/// it's returned in full.
+pub(crate) const fn last_chars(s: &str, mut char_count: usize) -> &[u8] {
+    let s_bytes = s.as_bytes();
+    let mut pos = s_bytes.len();
+    while pos > 0 && char_count > 0 {
+        pos -= 1;
+        // Skip UTF-8 continuation bytes.
+        while s_bytes[pos] >> 6 == 0b_10 {
+            pos -= 1;
+        }
+        char_count -= 1;
+    }
+    // SAFETY: `pos <= s_bytes.len()`, so the pointer and the length are in bounds.
+    unsafe { slice::from_raw_parts(s_bytes.as_ptr().add(pos), s_bytes.len() - pos) }
+}
+
+/// Decodes a char starting at the byte offset `pos` in a valid UTF-8 byte sequence. Returns
+/// the code point of the char and its length in bytes.
+pub(crate) const fn decode_char(s_bytes: &[u8], pos: usize) -> (u32, usize) {
+    const fn cont(byte: u8) -> u32 {
+        (byte & 0b_0011_1111) as u32
+    }
+
+    let first = s_bytes[pos];
+    if first < 128 {
+        (first as u32, 1)
+    } else if first >> 5 == 0b_110 {
+        let code = ((first & 0b_0001_1111) as u32) << 6 | cont(s_bytes[pos + 1]);
+        (code, 2)
+    } else if first >> 4 == 0b_1110 {
+        let code = ((first & 0b_0000_1111) as u32) << 12
+            | cont(s_bytes[pos + 1]) << 6
+            | cont(s_bytes[pos + 2]);
+        (code, 3)
+    } else if first >> 3 == 0b_11110 {
+        let code = ((first & 0b_0000_0111) as u32) << 18
+            | cont(s_bytes[pos + 1]) << 12
+            | cont(s_bytes[pos + 2]) << 6
+            | cont(s_bytes[pos + 3]);
+        (code, 4)
+    } else {
+        unreachable!() // Invalid UTF-8 encoding
+    }
+}
+
+/// Returns the maximum byte length of a char in a string, or 1 if the string is empty.
+pub(crate) const fn max_char_len(s: &str) -> usize {
+    let s_bytes = s.as_bytes();
+    let mut max_len = 1;
+    let mut pos = 0;
+    while pos < s_bytes.len() {
+        let (_, char_len) = decode_char(s_bytes, pos);
+        if char_len > max_len {
+            max_len = char_len;
+        }
+        pos += char_len;
+    }
+    max_len
 }
 
 /// Counts the number of chars in a string.
 pub(crate) const fn count_chars(s: &str) -> usize {
-    let s_bytes = s.as_bytes();
+    count_chars_in_bytes(s.as_bytes())
+}
+
+/// Counts the number of chars in a valid UTF-8 byte sequence.
+pub(crate) const fn count_chars_in_bytes(s_bytes: &[u8]) -> usize {
     let mut pos = 0;
     let mut char_count = 0;
     while pos < s_bytes.len() {
@@ -61,8 +203,42 @@ pub(crate) const fn count_chars(s: &str) -> usize {
     char_count
 }
 
+/// Checks whether `haystack` contains `needle` starting from the byte offset `start`.
+pub(crate) const fn bytes_match_at(haystack: &[u8], needle: &[u8], start: usize) -> bool {
+    if start > haystack.len() || haystack.len() - start < needle.len() {
+        return false;
+    }
+    let mut pos = 0;
+    while pos < needle.len() {
+        if haystack[start + pos] != needle[pos] {
+            return false;
+        }
+        pos += 1;
+    }
+    true
+}
+
+pub(crate) const fn is_ascii(bytes: &[u8]) -> bool {
+    let mut pos = 0;
+    while pos < bytes.len() {
+        if bytes[pos] >= 128 {
+            return false;
+        }
+        pos += 1;
+    }
+    true
+}
+
 pub(crate) const fn assert_is_ascii(s: &str) {
     const CLIP_LEN: usize = 32;
+    const REPLACER: &str = "…";
+    const PREFIX: &str = "String '";
+    const INFIX: &str = "' contains non-ASCII chars; first at position ";
+    const CAPACITY: usize = PREFIX.len()
+        + CLIP_LEN * 4
+        + REPLACER.len()
+        + INFIX.len()
+        + <usize as MaxLength>::MAX_LENGTH.bytes;
 
     let s_bytes = s.as_bytes();
     let mut pos = 0;
@@ -70,10 +246,22 @@ pub(crate) const fn assert_is_ascii(s: &str) {
         if s_bytes[pos] < 128 {
             pos += 1;
         } else {
-            crate::compile_panic!(
-                "String '", s => crate::clip(CLIP_LEN, "…"), "' contains non-ASCII chars; \
-                 first at position ", pos => crate::fmt::<usize>()
-            );
+            // The string is clipped manually rather than with `clip()`, since the latter calls this function
+            // to check the replacer, which would lead to a const evaluation cycle.
+            let (head, replacer) = match ClippedStr::new(s, CLIP_LEN) {
+                ClippedStr::Full(bytes) => (bytes, ""),
+                ClippedStr::Clipped(bytes) => (bytes, REPLACER),
+            };
+            // SAFETY: `ClippedStr` always retains whole chars, so `head` is valid UTF-8.
+            let head = unsafe { str::from_utf8_unchecked(head) };
+            let message = CompileArgs::<CAPACITY>::format(&[
+                ArgumentWrapper::new(PREFIX).into_argument(),
+                ArgumentWrapper::new(head).into_argument(),
+                ArgumentWrapper::new(replacer).into_argument(),
+                ArgumentWrapper::new(INFIX).into_argument(),
+                ArgumentWrapper::new(pos).into_argument(),
+            ]);
+            panic!("{}", message.as_str());
         }
     }
 }
@@ -82,6 +270,135 @@ pub(crate) const fn assert_is_ascii(s: &str) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn extracting_first_chars_with_byte_budget() {
+        assert_eq!(
+            ClippedStr::with_byte_budget("Test", 2),
+            ClippedStr::Clipped(b"Te")
+        );
+        assert_eq!(
+            ClippedStr::with_byte_budget("Test", 4),
+            ClippedStr::Full(b"Test")
+        );
+        assert_eq!(
+            ClippedStr::with_byte_budget("Tℝ💣eßt", 1),
+            ClippedStr::Clipped(b"T")
+        );
+        for max_bytes in 4..8 {
+            assert_eq!(
+                ClippedStr::with_byte_budget("Tℝ💣eßt", max_bytes),
+                ClippedStr::Clipped("Tℝ".as_bytes())
+            );
+        }
+        assert_eq!(
+            ClippedStr::with_byte_budget("Tℝ💣eßt", 8),
+            ClippedStr::Clipped("Tℝ💣".as_bytes())
+        );
+        assert_eq!(
+            ClippedStr::with_byte_budget("💣Test", 3),
+            ClippedStr::Clipped(b"")
+        );
+    }
+
+    #[test]
+    fn extracting_last_chars() {
+        assert_eq!(last_chars("Test", 0), b"");
+        assert_eq!(last_chars("Test", 1), b"t");
+        assert_eq!(last_chars("Test", 3), b"est");
+        assert_eq!(last_chars("Tℝ💣eßt", 2), "ßt".as_bytes());
+        assert_eq!(last_chars("Tℝ💣eßt", 4), "💣eßt".as_bytes());
+        assert_eq!(last_chars("Tℝ💣eßt", 5), "ℝ💣eßt".as_bytes());
+        for char_count in [6, 8, 32] {
+            assert_eq!(last_chars("Tℝ💣eßt", char_count), "Tℝ💣eßt".as_bytes());
+        }
+    }
+
+    #[test]
+    fn clipping_at_word_boundary() {
+        assert_eq!(clip_at_word_boundary("Test string", 6), b"Test");
+        assert_eq!(clip_at_word_boundary("Test string", 4), b"Test");
+        assert_eq!(clip_at_word_boundary("Test  string", 5), b"Test");
+        assert_eq!(clip_at_word_boundary("Test, string", 8), b"Test,");
+        assert_eq!(clip_at_word_boundary("well-known", 7), b"well-");
+        assert_eq!(clip_at_word_boundary("Test", 2), b"Te");
+        assert_eq!(clip_at_word_boundary("  Test", 4), b"  Te");
+        assert_eq!(
+            clip_at_word_boundary("Tℝ💣 eßt", "Tℝ💣 eß".len()),
+            "Tℝ💣".as_bytes()
+        );
+        assert_eq!(
+            clip_at_word_boundary("Tℝ💣eßt", "Tℝ💣e".len()),
+            "Tℝ💣e".as_bytes()
+        );
+    }
+
+    #[test]
+    fn decoding_chars() {
+        let s = "Tℝ💣eßt";
+        let mut pos = 0;
+        for expected in s.chars() {
+            let (code, len) = decode_char(s.as_bytes(), pos);
+            assert_eq!(code, u32::from(expected));
+            assert_eq!(len, expected.len_utf8());
+            pos += len;
+        }
+    }
+
+    #[test]
+    fn extracting_first_grapheme_clusters() {
+        assert_eq!(
+            ClippedStr::with_graphemes("Test", 2),
+            ClippedStr::Clipped(b"Te")
+        );
+        assert_eq!(
+            ClippedStr::with_graphemes("Test", 4),
+            ClippedStr::Full(b"Test")
+        );
+        // Combining acute accent
+        assert_eq!(
+            ClippedStr::with_graphemes("e\u{301}e\u{301}", 1),
+            ClippedStr::Clipped("e\u{301}".as_bytes())
+        );
+        // Emoji ZWJ sequence + skin tone modifier
+        let s = "👩🏽‍🚒!";
+        assert_eq!(
+            ClippedStr::with_graphemes(s, 1),
+            ClippedStr::Clipped("👩🏽‍🚒".as_bytes())
+        );
+        // Flags
+        assert_eq!(
+            ClippedStr::with_graphemes("🇺🇸🇩🇪🇫🇷", 2),
+            ClippedStr::Clipped("🇺🇸🇩🇪".as_bytes())
+        );
+        // Hangul jamo
+        assert_eq!(
+            ClippedStr::with_graphemes("\u{1100}\u{1161}\u{11a8}한", 1),
+            ClippedStr::Clipped("\u{1100}\u{1161}\u{11a8}".as_bytes())
+        );
+        assert_eq!(
+            ClippedStr::with_graphemes("\r\n\n", 1),
+            ClippedStr::Clipped(b"\r\n")
+        );
+        // Too long clusters are split.
+        let s = "a\u{301}\u{302}\u{303}\u{304}\u{305}\u{306}\u{307}\u{308}";
+        assert_eq!(
+            ClippedStr::with_graphemes(s, 1),
+            ClippedStr::Clipped("a\u{301}\u{302}\u{303}\u{304}\u{305}\u{306}\u{307}".as_bytes())
+        );
+    }
+
+    #[test]
+    fn matching_bytes() {
+        assert!(bytes_match_at(b"test", b"", 0));
+        assert!(bytes_match_at(b"test", b"", 4));
+        assert!(!bytes_match_at(b"test", b"", 5));
+        assert!(bytes_match_at(b"test", b"te", 0));
+        assert!(bytes_match_at(b"test", b"st", 2));
+        assert!(!bytes_match_at(b"test", b"st", 1));
+        assert!(!bytes_match_at(b"test", b"st", 3));
+        assert!(!bytes_match_at(b"test", b"tests", 0));
+    }
+
     #[test]
     fn extracting_first_chars_from_ascii_string() {
         assert_eq!(ClippedStr::new("Test", 1), ClippedStr::Clipped(b"T"));